@@ -0,0 +1,21 @@
+use std::env;
+
+use clap::Parser;
+
+use super::Document;
+use crate::{course::shell_command, Error, Settings};
+
+#[derive(Parser)]
+pub struct Args {}
+
+pub fn run(settings: &Settings, document: &Document, _: Args) -> color_eyre::Result<()> {
+    let editor = env::var("EDITOR").map_err(|_| Error::OpenerMissing("$EDITOR is not set"))?;
+
+    let status = shell_command(&editor, &document.path(settings)).status()?;
+
+    if !status.success() {
+        Err(Error::OpenerFailed(status.to_string()))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use clap::Parser;
+
+use super::Document;
+use crate::{Error, Format, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    /// The key to print (e.g. `profile.name`)
+    key: String,
+}
+
+pub fn run(
+    settings: &Settings,
+    document: &Document,
+    format: Format,
+    args: Args,
+) -> color_eyre::Result<()> {
+    let value = document.load(settings)?;
+
+    let value = super::get(&value, &args.key).ok_or(Error::ConfigKeyDoesNotExist(args.key))?;
+
+    format.print(value, super::human)
+}
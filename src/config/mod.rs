@@ -0,0 +1,164 @@
+mod edit;
+mod get;
+mod set;
+mod show;
+mod unset;
+
+use std::{fs, path::PathBuf};
+
+use clap::Parser;
+use serde_yaml::{Mapping, Value};
+
+use crate::{Course, Error, Format, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    #[clap(subcommand)]
+    command: Command,
+
+    /// Read and change this course's `course.yml` instead of the settings file
+    #[clap(short, long, global = true, aliases = ["course", "code"])]
+    course_code: Option<String>,
+}
+
+#[derive(Parser)]
+enum Command {
+    /// Print the value of a key (e.g. `profile.name`)
+    Get(get::Args),
+
+    /// Set the value of a key (e.g. `profile.name`)
+    Set(set::Args),
+
+    /// Remove a key
+    #[clap(aliases = ["rm", "remove"])]
+    Unset(unset::Args),
+
+    /// Print the whole settings file
+    Show(show::Args),
+
+    /// Open the settings file in `$EDITOR`
+    Edit(edit::Args),
+}
+
+pub fn run(settings: &Settings, format: Format, args: Args) -> color_eyre::Result<()> {
+    let document = match args.course_code {
+        Some(code) => Document::Course(Box::new(
+            Course::open(settings, &code)?.ok_or(Error::CourseDoesNotExist(code))?,
+        )),
+        None => Document::Settings,
+    };
+
+    match args.command {
+        Command::Get(args) => get::run(settings, &document, format, args),
+        Command::Set(args) => set::run(settings, &document, args),
+        Command::Unset(args) => unset::run(settings, &document, args),
+        Command::Show(args) => show::run(settings, &document, format, args),
+        Command::Edit(args) => edit::run(settings, &document, args),
+    }
+}
+
+/// The file `unii config` reads and changes.
+enum Document {
+    Settings,
+    Course(Box<Course>),
+}
+
+impl Document {
+    fn path(&self, settings: &Settings) -> PathBuf {
+        match self {
+            Self::Settings => settings.file.clone(),
+            Self::Course(course) => course.yaml_path(settings),
+        }
+    }
+
+    fn load(&self, settings: &Settings) -> color_eyre::Result<Value> {
+        Ok(match self {
+            Self::Settings => serde_yaml::to_value(settings)?,
+            Self::Course(course) => serde_yaml::to_value(&**course)?,
+        })
+    }
+
+    /// Check that `value` is still a valid settings file or course, and drop anything it would
+    /// not keep.
+    fn normalize(&self, value: Value) -> color_eyre::Result<Value> {
+        Ok(match self {
+            Self::Settings => serde_yaml::to_value(serde_yaml::from_value::<Settings>(value)?)?,
+            Self::Course(_) => serde_yaml::to_value(serde_yaml::from_value::<Course>(value)?)?,
+        })
+    }
+
+    fn store(&self, settings: &Settings, value: Value) -> color_eyre::Result<()> {
+        match self {
+            Self::Settings => Settings {
+                file: settings.file.clone(),
+                ..serde_yaml::from_value(value)?
+            }
+            .save(),
+            Self::Course(course) => {
+                fs::write(course.yaml_path(settings), serde_yaml::to_string(&value)?)?;
+
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Look up a dotted key like `profile.name` in `value`.
+fn get<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+    key.split('.')
+        .try_fold(value, |value, part| value.as_mapping()?.get(part))
+}
+
+/// Set a dotted key like `profile.name` in `value`, creating mappings along the way.
+fn set(value: &mut Value, key: &str, new: Value) -> Result<(), Error> {
+    let mut parts = key.split('.').peekable();
+    let mut current = value;
+
+    while let Some(part) = parts.next() {
+        if current.is_null() {
+            *current = Value::Mapping(Mapping::new());
+        }
+
+        let mapping = current
+            .as_mapping_mut()
+            .ok_or_else(|| Error::ConfigKeyNotAMapping(key.to_owned()))?;
+
+        if parts.peek().is_none() {
+            mapping.insert(part.into(), new);
+
+            return Ok(());
+        }
+
+        current = mapping
+            .entry(part.into())
+            .or_insert(Value::Mapping(Mapping::new()));
+    }
+
+    Ok(())
+}
+
+/// Remove a dotted key like `profile.name` from `value`, returning whether it was there.
+fn unset(value: &mut Value, key: &str) -> bool {
+    let (parent, last) = match key.rsplit_once('.') {
+        Some((parent, last)) => (parent.split('.').collect::<Vec<_>>(), last),
+        None => (Vec::new(), key),
+    };
+
+    let parent = parent
+        .into_iter()
+        .try_fold(value, |value, part| value.as_mapping_mut()?.get_mut(part));
+
+    parent
+        .and_then(Value::as_mapping_mut)
+        .and_then(|mapping| mapping.remove(last))
+        .is_some()
+}
+
+/// Print a value found in a document: scalars as plain text, anything else as YAML.
+fn human(value: &Value) -> String {
+    match value {
+        Value::String(s) => format!("{s}\n"),
+        Value::Null => String::new(),
+        _ => serde_yaml::to_string(value).expect("a value should serialize"),
+    }
+}
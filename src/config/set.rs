@@ -0,0 +1,100 @@
+use clap::Parser;
+use serde_yaml::Value;
+
+use super::Document;
+use crate::{Error, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    /// The key to set (e.g. `profile.name`)
+    key: String,
+
+    /// The value to set, read as YAML (e.g. `true`, `3` or `[a, b]`) where that keeps it intact
+    value: String,
+}
+
+pub fn run(
+    settings: &Settings,
+    document: &Document,
+    Args { key, value: raw }: Args,
+) -> color_eyre::Result<()> {
+    let original = document.load(settings)?;
+
+    let mut value = original.clone();
+    super::set(&mut value, &key, parse(&raw))?;
+
+    // Fall back to a string for fields like `grading-scale: 4.33`
+    let value = match document.normalize(value) {
+        Ok(value) => value,
+        Err(err) => {
+            let mut value = original;
+            super::set(&mut value, &key, Value::String(raw))?;
+
+            document.normalize(value).map_err(|_| err)?
+        }
+    };
+
+    // Keys the settings file does not know about are dropped rather than saved
+    if super::get(&value, &key).is_none() {
+        Err(Error::ConfigKeyDoesNotExist(key.clone()))?;
+    }
+
+    document.store(settings, value)?;
+
+    println!("Set {key} in {}", document.path(settings).display());
+
+    Ok(())
+}
+
+/// Read `raw` as YAML, unless that would change it, e.g. by dropping the leading zeros of a
+/// student number.
+fn parse(raw: &str) -> Value {
+    match serde_yaml::from_str::<Value>(raw) {
+        Ok(Value::Number(number)) if number.to_string() != raw => Value::String(raw.to_owned()),
+        Ok(Value::String(_) | Value::Null) | Err(_) => Value::String(raw.to_owned()),
+        Ok(value) => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use serde_yaml::Value;
+
+    use super::parse;
+    use crate::{config, Settings};
+
+    #[test]
+    fn keeps_strings_that_look_like_numbers() {
+        assert_eq!(parse("012345"), Value::String("012345".to_owned()));
+        assert_eq!(parse("12345"), Value::from(12345));
+        assert_eq!(parse("true"), Value::Bool(true));
+    }
+
+    #[test]
+    fn leading_zeros_survive_a_save_and_load() {
+        let file = env::temp_dir().join(format!("unii-settings-{}.yml", process::id()));
+
+        let mut value =
+            serde_yaml::to_value(Settings::default()).expect("settings should serialize");
+        config::set(&mut value, "profile.student-number", parse("012345"))
+            .expect("key should be settable");
+        let settings = serde_yaml::from_value::<Settings>(value).expect("settings should be valid");
+
+        fs::write(
+            &file,
+            serde_yaml::to_string(&settings).expect("settings should serialize"),
+        )
+        .expect("settings should be writable");
+        let loaded = Settings::try_open_from(&file)
+            .expect("settings should load")
+            .expect("settings file should exist");
+        fs::remove_file(&file).expect("settings file should be removable");
+
+        assert_eq!(
+            loaded.profile.get("student-number"),
+            Some(&Value::String("012345".to_owned()))
+        );
+    }
+}
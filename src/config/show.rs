@@ -0,0 +1,18 @@
+use clap::Parser;
+
+use super::Document;
+use crate::{Format, Settings};
+
+#[derive(Parser)]
+pub struct Args {}
+
+pub fn run(
+    settings: &Settings,
+    document: &Document,
+    format: Format,
+    _: Args,
+) -> color_eyre::Result<()> {
+    let value = document.load(settings)?;
+
+    format.print(&value, super::human)
+}
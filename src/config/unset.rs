@@ -0,0 +1,24 @@
+use clap::Parser;
+
+use super::Document;
+use crate::{Error, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    /// The key to remove (e.g. `profile.name`)
+    key: String,
+}
+
+pub fn run(settings: &Settings, document: &Document, Args { key }: Args) -> color_eyre::Result<()> {
+    let mut value = document.load(settings)?;
+
+    if !super::unset(&mut value, &key) {
+        Err(Error::ConfigKeyDoesNotExist(key.clone()))?;
+    }
+
+    document.store(settings, document.normalize(value)?)?;
+
+    println!("Unset {key} in {}", document.path(settings).display());
+
+    Ok(())
+}
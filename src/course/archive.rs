@@ -0,0 +1,29 @@
+use clap::Parser;
+
+use super::Course;
+use crate::{Error, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    /// The course code of the course (defaults to the course containing the working directory)
+    course_code: Option<String>,
+
+    /// The term to archive the course under (defaults to the term of the course)
+    #[clap(short, long)]
+    term: Option<String>,
+}
+
+pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
+    let course = Course::open_or_current(settings, args.course_code)?;
+
+    let term = args
+        .term
+        .or_else(|| course.term.clone())
+        .ok_or_else(|| Error::CourseTermMissing(course.code().to_owned()))?;
+
+    course.archive(settings, &term)?;
+
+    println!("Archived course: {} ({term})", course.code());
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use clap::{Parser, ValueEnum};
+
+use super::{fields::Fields, Course};
+use crate::{gpa::Outcome, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    /// The course code of the course (defaults to the course containing the working directory)
+    course_code: Option<String>,
+
+    #[clap(flatten)]
+    fields: Fields,
+
+    /// A field to remove from the course (can be repeated)
+    #[clap(short, long, value_enum)]
+    unset: Vec<Field>,
+
+    /// An extra field to remove from the course (can be repeated)
+    #[clap(long, name = "KEY")]
+    unset_extra: Vec<String>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Field {
+    Name,
+    Instructors,
+    Tas,
+    Term,
+    Credits,
+    Section,
+    Website,
+    Lms,
+    Color,
+    FinalGrade,
+    Outcome,
+    Extra,
+}
+
+pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
+    let mut course = Course::open_or_current(settings, args.course_code)?;
+
+    for field in args.unset {
+        match field {
+            Field::Name => course.name = None,
+            Field::Instructors => course.instructors.clear(),
+            Field::Tas => course.tas.clear(),
+            Field::Term => course.term = None,
+            Field::Credits => course.credits = None,
+            Field::Section => course.section = None,
+            Field::Website => course.website = None,
+            Field::Lms => course.lms = None,
+            Field::Color => course.color = None,
+            Field::FinalGrade => course.final_grade = None,
+            Field::Outcome => course.outcome = Outcome::default(),
+            Field::Extra => course.extra.clear(),
+        }
+    }
+
+    for key in args.unset_extra {
+        course.extra.remove(&key);
+    }
+
+    args.fields.apply(&mut course);
+
+    course.write(settings)?;
+
+    println!("Updated course: {}", course.code());
+
+    Ok(())
+}
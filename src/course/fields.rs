@@ -0,0 +1,101 @@
+use clap::Parser;
+
+use super::Course;
+use crate::gpa::Outcome;
+
+/// Command line flags for the metadata stored in `course.yml`.
+#[derive(Parser)]
+pub struct Fields {
+    /// The name of the course
+    #[clap(short, long)]
+    name: Option<String>,
+
+    /// An instructor of the course (can be repeated)
+    #[clap(short, long = "instructor")]
+    instructors: Vec<String>,
+
+    /// A teaching assistant of the course (can be repeated)
+    #[clap(long = "ta")]
+    tas: Vec<String>,
+
+    /// The term the course is taken in
+    #[clap(short, long)]
+    term: Option<String>,
+
+    /// The number of credits the course is worth
+    #[clap(long)]
+    credits: Option<f64>,
+
+    /// The section of the course
+    #[clap(short, long)]
+    section: Option<String>,
+
+    /// The URL of the course website
+    #[clap(short, long)]
+    website: Option<String>,
+
+    /// The URL of the course on the learning management system
+    #[clap(long)]
+    lms: Option<String>,
+
+    /// The final percentage, overriding the one computed from the grade book
+    #[clap(long)]
+    final_grade: Option<f64>,
+
+    /// How the course finished, for the purposes of GPA
+    #[clap(long, value_enum)]
+    outcome: Option<Outcome>,
+
+    /// The display color of the course
+    #[clap(long)]
+    color: Option<String>,
+
+    /// An extra field to store with the course (can be repeated)
+    #[clap(short, long, value_parser = parse_extra, name = "KEY=VALUE")]
+    extra: Vec<(String, serde_yaml::Value)>,
+}
+
+fn parse_extra(s: &str) -> Result<(String, serde_yaml::Value), String> {
+    let (key, value) = s
+        .split_once('=')
+        .ok_or("invalid extra field: missing '='")?;
+
+    let value = serde_yaml::from_str(value)
+        .unwrap_or_else(|_| serde_yaml::Value::String(value.to_string()));
+
+    Ok((key.to_string(), value))
+}
+
+impl Fields {
+    /// Overwrite every field of `course` which was given on the command line.
+    pub fn apply(self, course: &mut Course) {
+        fn set<T>(field: &mut Option<T>, value: Option<T>) {
+            if value.is_some() {
+                *field = value;
+            }
+        }
+
+        set(&mut course.name, self.name);
+        set(&mut course.term, self.term);
+        set(&mut course.credits, self.credits);
+        set(&mut course.section, self.section);
+        set(&mut course.website, self.website);
+        set(&mut course.lms, self.lms);
+        set(&mut course.color, self.color);
+        set(&mut course.final_grade, self.final_grade);
+
+        if let Some(outcome) = self.outcome {
+            course.outcome = outcome;
+        }
+
+        if !self.instructors.is_empty() {
+            course.instructors = self.instructors;
+        }
+
+        if !self.tas.is_empty() {
+            course.tas = self.tas;
+        }
+
+        course.extra.extend(self.extra);
+    }
+}
@@ -1,15 +1,106 @@
 use clap::Parser;
+use serde::Serialize;
 
 use super::Course;
-use crate::Settings;
+use crate::{format::Table, Format, Settings};
 
 #[derive(Parser)]
-pub struct Args {}
+pub struct Args {
+    /// Include archived courses
+    #[clap(short, long)]
+    archived: bool,
 
-pub fn run(settings: &Settings, _: Args) -> color_eyre::Result<()> {
-    for course in Course::all(settings)? {
-        println!("{}", course?.code());
+    /// Only list courses in this term (defaults to the active courses: those in the current term
+    /// or without a term)
+    #[clap(short, long, conflicts_with = "all")]
+    term: Option<String>,
+
+    /// List courses in every term
+    #[clap(long)]
+    all: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Listing {
+    code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archived_term: Option<String>,
+    #[serde(flatten)]
+    course: Course,
+}
+
+pub fn run(
+    settings: &Settings,
+    format: Format,
+    Args {
+        archived,
+        term,
+        all,
+    }: Args,
+) -> color_eyre::Result<()> {
+    // Archived courses are only filtered when a term is given explicitly, since they are
+    // usually from past terms.
+    let archived_term_filter = term.clone();
+
+    let courses = if all {
+        Course::all(settings)?.collect::<color_eyre::Result<Vec<_>>>()?
+    } else if let Some(term) = &term {
+        Course::all(settings)?
+            .filter(|course| {
+                course
+                    .as_ref()
+                    .map_or(true, |course| course.term.as_ref() == Some(term))
+            })
+            .collect::<color_eyre::Result<Vec<_>>>()?
+    } else {
+        Course::active(settings)?
+    };
+
+    let mut listings = courses
+        .into_iter()
+        .map(|course| Listing {
+            code: course.code().to_owned(),
+            archived_term: None,
+            course,
+        })
+        .collect::<Vec<_>>();
+
+    listings.sort_by(|a, b| a.code.cmp(&b.code));
+
+    if archived {
+        listings.extend(
+            Course::archived(settings)?
+                .into_iter()
+                .filter(|(term, _)| {
+                    archived_term_filter
+                        .as_ref()
+                        .map_or(true, |filter| filter == term)
+                })
+                .map(|(term, course)| Listing {
+                    code: course.code().to_owned(),
+                    archived_term: Some(term),
+                    course,
+                }),
+        );
     }
 
-    Ok(())
+    format.print(&listings, |listings| {
+        let mut table = Table::new(vec!["code", "name", "term", "instructors", "status"]);
+
+        for listing in listings {
+            table.push(vec![
+                listing.code.clone(),
+                listing.course.name.clone().unwrap_or_default(),
+                listing.course.term.clone().unwrap_or_default(),
+                listing.course.instructors.join(", "),
+                listing
+                    .archived_term
+                    .as_ref()
+                    .map_or_else(|| "active".to_owned(), |term| format!("archived ({term})")),
+            ]);
+        }
+
+        table.to_string()
+    })
 }
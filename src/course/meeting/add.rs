@@ -0,0 +1,101 @@
+use chrono::{NaiveTime, Weekday};
+use clap::Parser;
+
+use crate::{
+    schedule::{parse_time, Meeting, MeetingKind},
+    Course, Error, Settings,
+};
+
+#[derive(Parser)]
+pub struct Args {
+    /// The course code of the course (defaults to the course containing the working directory)
+    course_code: Option<String>,
+
+    /// The kind of meeting
+    #[clap(short, long, value_enum)]
+    kind: MeetingKind,
+
+    /// The day of the week the meeting is on
+    #[clap(short, long)]
+    day: Weekday,
+
+    /// The time the meeting starts (HH:MM)
+    #[clap(short, long, value_parser = parse_time)]
+    start: NaiveTime,
+
+    /// The time the meeting ends (HH:MM)
+    #[clap(short, long, value_parser = parse_time)]
+    end: NaiveTime,
+
+    /// The building or campus the meeting is in
+    #[clap(short, long)]
+    location: Option<String>,
+
+    /// The room the meeting is in
+    #[clap(short, long)]
+    room: Option<String>,
+
+    /// Add the meeting even if it clashes with another meeting
+    #[clap(short, long)]
+    force: bool,
+}
+
+pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
+    let mut course = Course::open_or_current(settings, args.course_code)?;
+
+    if args.end <= args.start {
+        Err(Error::MeetingEndsBeforeStart)?;
+    }
+
+    let meeting = Meeting {
+        kind: args.kind,
+        day: args.day,
+        start: args.start,
+        end: args.end,
+        location: args.location,
+        room: args.room,
+    };
+
+    let others = Course::active(settings)?
+        .into_iter()
+        .filter(|other| other.code() != course.code())
+        .collect::<Vec<_>>();
+
+    let clashes = others
+        .iter()
+        .chain([&course])
+        .flat_map(|other| {
+            other
+                .meetings
+                .iter()
+                .filter(|other_meeting| other_meeting.overlaps(&meeting))
+                .map(|other_meeting| {
+                    format!(
+                        "{} {} on {} at {}-{}",
+                        other.code(),
+                        other_meeting.kind,
+                        other_meeting.day,
+                        other_meeting.start.format("%H:%M"),
+                        other_meeting.end.format("%H:%M"),
+                    )
+                })
+        })
+        .collect::<Vec<_>>();
+
+    if !clashes.is_empty() {
+        if args.force {
+            for clash in &clashes {
+                println!("Warning: clashes with {clash}");
+            }
+        } else {
+            Err(Error::MeetingClash(clashes.join(", ")))?;
+        }
+    }
+
+    course.meetings.push(meeting);
+    course.write(settings)?;
+
+    println!("Added meeting to course: {}", course.code());
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use clap::Parser;
+
+use crate::{format::Table, Course, Format, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    /// The course code of the course (defaults to the course containing the working directory)
+    course_code: Option<String>,
+}
+
+pub fn run(settings: &Settings, format: Format, args: Args) -> color_eyre::Result<()> {
+    let course = Course::open_or_current(settings, args.course_code)?;
+
+    format.print(&course.meetings, |meetings| {
+        let mut table = Table::new(vec!["#", "day", "time", "kind", "place"]);
+
+        for (index, meeting) in meetings.iter().enumerate() {
+            table.push(vec![
+                (index + 1).to_string(),
+                meeting.day.to_string(),
+                format!(
+                    "{}-{}",
+                    meeting.start.format("%H:%M"),
+                    meeting.end.format("%H:%M")
+                ),
+                meeting.kind.to_string(),
+                meeting.place(),
+            ]);
+        }
+
+        table.to_string()
+    })
+}
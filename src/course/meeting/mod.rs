@@ -0,0 +1,36 @@
+mod add;
+mod list;
+mod remove;
+
+use clap::Parser;
+
+use crate::{Format, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Parser)]
+enum Command {
+    /// Add a weekly meeting to a course
+    #[clap(aliases = ["new", "create"])]
+    Add(add::Args),
+
+    /// List the weekly meetings of a course
+    #[clap(aliases = ["ls"])]
+    List(list::Args),
+
+    /// Remove a weekly meeting from a course
+    #[clap(aliases = ["rm", "delete"])]
+    Remove(remove::Args),
+}
+
+pub fn run(settings: &Settings, format: Format, args: Args) -> color_eyre::Result<()> {
+    match args.command {
+        Command::Add(args) => add::run(settings, args),
+        Command::List(args) => list::run(settings, format, args),
+        Command::Remove(args) => remove::run(settings, args),
+    }
+}
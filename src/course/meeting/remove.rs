@@ -0,0 +1,31 @@
+use clap::Parser;
+
+use crate::{Course, Error, Settings};
+
+#[derive(Parser)]
+#[clap(allow_missing_positional = true)]
+pub struct Args {
+    /// The course code of the course (defaults to the course containing the working directory)
+    course_code: Option<String>,
+
+    /// The number of the meeting, as shown by `course meeting list`
+    number: usize,
+}
+
+pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
+    let mut course = Course::open_or_current(settings, args.course_code)?;
+
+    if args.number == 0 || args.number > course.meetings.len() {
+        Err(Error::MeetingDoesNotExist(
+            course.code().to_owned(),
+            args.number,
+        ))?;
+    }
+
+    course.meetings.remove(args.number - 1);
+    course.write(settings)?;
+
+    println!("Removed meeting from course: {}", course.code());
+
+    Ok(())
+}
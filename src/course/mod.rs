@@ -1,12 +1,25 @@
+mod archive;
+mod edit;
+mod fields;
 mod list;
+mod meeting;
 mod new;
+mod open;
+mod remove;
+mod rename;
+mod show;
 mod types;
+mod unarchive;
 
 use clap::Parser;
 
-use crate::Settings;
+use crate::{Format, Settings};
 
-pub use self::types::{Course, COURSE_YAML};
+pub use self::{
+    open::shell_command,
+    show::format_size,
+    types::{Course, COURSE_YAML},
+};
 
 #[derive(Parser)]
 pub struct Args {
@@ -20,14 +33,49 @@ enum Command {
     #[clap(aliases = ["create", "add"])]
     New(new::Args),
 
+    /// Edit the metadata of a course
+    Edit(edit::Args),
+
     /// List all courses
     #[clap(aliases = ["ls"])]
     List(list::Args),
+
+    /// Open a course in an editor, shell or file manager
+    Open(open::Args),
+
+    /// Show everything known about a course
+    Show(show::Args),
+
+    /// Change the course code of a course
+    #[clap(aliases = ["mv"])]
+    Rename(rename::Args),
+
+    /// Manage the weekly meetings of a course
+    #[clap(aliases = ["meetings", "schedule"])]
+    Meeting(meeting::Args),
+
+    /// Move a course into the trash
+    #[clap(aliases = ["rm", "delete"])]
+    Remove(remove::Args),
+
+    /// Move a course into the archive
+    Archive(archive::Args),
+
+    /// Restore a course from the archive
+    Unarchive(unarchive::Args),
 }
 
-pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
+pub fn run(settings: &Settings, format: Format, args: Args) -> color_eyre::Result<()> {
     match args.command {
         Command::New(args) => new::run(settings, args),
-        Command::List(args) => list::run(settings, args),
+        Command::Edit(args) => edit::run(settings, args),
+        Command::List(args) => list::run(settings, format, args),
+        Command::Open(args) => open::run(settings, args),
+        Command::Show(args) => show::run(settings, format, args),
+        Command::Rename(args) => rename::run(settings, args),
+        Command::Meeting(args) => meeting::run(settings, format, args),
+        Command::Remove(args) => remove::run(settings, args),
+        Command::Archive(args) => archive::run(settings, args),
+        Command::Unarchive(args) => unarchive::run(settings, args),
     }
 }
@@ -1,23 +1,26 @@
 use clap::Parser;
 
-use super::Course;
-use crate::Settings;
+use super::{fields::Fields, Course};
+use crate::{Settings, Term};
 
 #[derive(Parser)]
 pub struct Args {
     /// The course code of the course
     course_code: String,
 
-    /// The name of the course
-    #[clap(short, long)]
-    name: Option<String>,
+    #[clap(flatten)]
+    fields: Fields,
 }
 
 pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
-    let course = match args.name {
-        Some(name) => Course::new(settings, args.course_code, name)?,
-        None => Course::from_code(settings, args.course_code)?,
-    };
+    let mut course = Course::new(args.course_code);
+    args.fields.apply(&mut course);
+
+    if course.term.is_none() {
+        course.term = Term::current(settings)?.map(|term| term.name().to_owned());
+    }
+
+    course.create(settings)?;
 
     println!("Created course: {}", course.code());
 
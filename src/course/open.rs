@@ -0,0 +1,154 @@
+use std::{
+    convert::Infallible,
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use clap::{Parser, ValueEnum};
+
+use super::Course;
+use crate::{Error, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    /// The course code, optionally followed by a path to an item inside the course (defaults to
+    /// the course and item containing the working directory)
+    #[clap(value_parser = parse_course_path, name = "[COURSE_CODE][:PATH]")]
+    target: Option<(Option<String>, Option<PathBuf>)>,
+
+    /// Print the path instead of opening it
+    #[clap(short, long)]
+    print: bool,
+
+    /// What to open the course with (defaults to the `opener` setting, then `$EDITOR`)
+    #[clap(short, long, value_enum)]
+    with: Option<Opener>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Opener {
+    /// Open the path in `$EDITOR`
+    Editor,
+
+    /// Start `$SHELL` inside the path
+    Shell,
+
+    /// Open the path with the `opener` command from the settings file
+    Custom,
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn parse_course_path(s: &str) -> Result<(Option<String>, Option<PathBuf>), Infallible> {
+    let (course_code, path) = s.split_once(':').map_or_else(
+        || (s, None),
+        |(course_code, path)| (course_code, Some(PathBuf::from(path))),
+    );
+
+    Ok((
+        (!course_code.is_empty()).then(|| course_code.to_owned()),
+        path,
+    ))
+}
+
+/// The path of the working directory inside `course`, if it is inside `course` at all.
+fn current_item(settings: &Settings, course: &Course) -> Option<PathBuf> {
+    let cwd = env::current_dir().ok()?.canonicalize().ok()?;
+    let dir = course.dir(settings).canonicalize().ok()?;
+
+    cwd.strip_prefix(dir)
+        .ok()
+        .filter(|item| !item.as_os_str().is_empty())
+        .map(Path::to_path_buf)
+}
+
+pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
+    let (course_code, item) = args.target.unwrap_or_default();
+
+    let inferred = course_code.is_none();
+    let course = Course::open_or_current(settings, course_code)?;
+
+    let item = item.or_else(|| {
+        if inferred {
+            current_item(settings, &course)
+        } else {
+            None
+        }
+    });
+
+    let mut path = course.dir(settings);
+
+    if let Some(item) = item {
+        path.push(&item);
+
+        if !path.exists() {
+            Err(Error::CoursePathDoesNotExist(
+                course.code().to_owned(),
+                item.display().to_string(),
+            ))?;
+        }
+    }
+
+    if args.print {
+        println!("{}", path.display());
+
+        return Ok(());
+    }
+
+    let opener = args.with.unwrap_or_else(|| {
+        if settings.opener.is_some() {
+            Opener::Custom
+        } else {
+            Opener::Editor
+        }
+    });
+
+    let mut command = match opener {
+        Opener::Editor => {
+            let editor =
+                env::var("EDITOR").map_err(|_| Error::OpenerMissing("$EDITOR is not set"))?;
+
+            shell_command(&editor, &path)
+        }
+        Opener::Shell => {
+            let shell = env::var("SHELL").map_err(|_| Error::OpenerMissing("$SHELL is not set"))?;
+            let dir = if path.is_dir() {
+                path.as_path()
+            } else {
+                path.parent().unwrap_or(&path)
+            };
+
+            let mut command = Command::new(shell);
+            command.current_dir(dir);
+            command
+        }
+        Opener::Custom => {
+            let opener = settings.opener.as_deref().ok_or(Error::OpenerMissing(
+                "`opener` is not set in the settings file",
+            ))?;
+
+            shell_command(opener, &path)
+        }
+    };
+
+    let status = command.status()?;
+
+    if !status.success() {
+        Err(Error::OpenerFailed(status.to_string()))?;
+    }
+
+    Ok(())
+}
+
+/// Build a command which runs `program` through `sh`, passing `path` as its final argument.
+///
+/// This lets `$EDITOR` and the `opener` setting contain their own arguments (e.g. `code -w`).
+pub fn shell_command(program: &str, path: &Path) -> Command {
+    let mut command = Command::new("sh");
+    command
+        .arg("-c")
+        .arg(format!("{program} \"$1\""))
+        .arg("sh")
+        .arg(path);
+    command
+}
@@ -0,0 +1,42 @@
+use clap::Parser;
+
+use super::{
+    show::{dir_size, format_size},
+    Course,
+};
+use crate::{prompt, trash::TrashedCourse, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    /// The course code of the course (defaults to the course containing the working directory)
+    course_code: Option<String>,
+
+    /// Do not ask for confirmation
+    #[clap(short, long)]
+    yes: bool,
+}
+
+pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
+    let course = Course::open_or_current(settings, args.course_code)?;
+
+    let dir = course.dir(settings);
+
+    println!("This will move {} to the trash:", course.code());
+    println!("  {} ({})", dir.display(), format_size(dir_size(&dir)?));
+
+    if !args.yes && !prompt::confirm("Continue?")? {
+        println!("Aborted");
+
+        return Ok(());
+    }
+
+    TrashedCourse::trash(settings, &course)?;
+
+    println!(
+        "Removed course: {} (use `unii trash restore {}` to undo)",
+        course.code(),
+        course.code()
+    );
+
+    Ok(())
+}
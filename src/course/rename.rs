@@ -0,0 +1,156 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use clap::Parser;
+
+use super::Course;
+use crate::Settings;
+
+#[derive(Parser)]
+#[clap(allow_missing_positional = true)]
+pub struct Args {
+    /// The current course code of the course (defaults to the course containing the working
+    /// directory)
+    old_code: Option<String>,
+
+    /// The new course code of the course
+    new_code: String,
+}
+
+pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
+    let mut course = Course::open_or_current(settings, args.old_code)?;
+    let old_code = course.code().to_owned();
+
+    course.rename(settings, args.new_code)?;
+
+    println!("Renamed course: {old_code} -> {}", course.code());
+
+    let unii_dir = course.unii_dir(settings);
+
+    for path in files(&unii_dir)? {
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+
+        if let Some(content) = replace_code(&content, &old_code, course.code()) {
+            fs::write(&path, content)?;
+
+            println!("Rewrote: {}", path.display());
+        }
+    }
+
+    let stale = files(&course.dir(settings))?
+        .into_iter()
+        .filter(|path| !path.starts_with(&unii_dir))
+        .filter(|path| {
+            fs::read_to_string(path)
+                .is_ok_and(|content| replace_code(&content, &old_code, "").is_some())
+        })
+        .collect::<Vec<_>>();
+
+    if !stale.is_empty() {
+        println!();
+        println!("These files still mention {old_code} and may need to be re-rendered:");
+
+        for path in stale {
+            println!("  {}", path.display());
+        }
+    }
+
+    Ok(())
+}
+
+/// Every file below `dir`, recursively.
+fn files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+
+        if file_type.is_dir() {
+            files.extend(self::files(&entry.path())?);
+        } else if file_type.is_file() {
+            files.push(entry.path());
+        }
+    }
+
+    Ok(files)
+}
+
+/// Replace every whole-word occurrence of `old` in `text` with `new`.
+///
+/// Returns `None` if `text` does not mention `old`, so that `CS10` is not found inside `CS101`.
+fn replace_code(text: &str, old: &str, new: &str) -> Option<String> {
+    if old.is_empty() {
+        return None;
+    }
+
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+
+    let mut replaced = String::with_capacity(text.len());
+    let mut rest = text;
+    let mut found = false;
+
+    while let Some(index) = rest.find(old) {
+        let before = rest[..index]
+            .chars()
+            .last()
+            .or_else(|| replaced.chars().last());
+        let after = rest[index + old.len()..].chars().next();
+
+        replaced.push_str(&rest[..index]);
+
+        if before.is_some_and(is_word) || after.is_some_and(is_word) {
+            replaced.push_str(old);
+        } else {
+            replaced.push_str(new);
+            found = true;
+        }
+
+        rest = &rest[index + old.len()..];
+    }
+
+    replaced.push_str(rest);
+
+    found.then_some(replaced)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::replace_code;
+
+    #[test]
+    fn replaces_whole_words() {
+        assert_eq!(
+            replace_code("CS101: intro (CS101)", "CS101", "CS102").as_deref(),
+            Some("CS102: intro (CS102)")
+        );
+    }
+
+    #[test]
+    fn ignores_codes_inside_longer_words() {
+        assert_eq!(replace_code("CS1010 and XCS101", "CS101", "CS102"), None);
+        assert_eq!(
+            replace_code("CS1010 and CS101", "CS101", "CS102").as_deref(),
+            Some("CS1010 and CS102")
+        );
+    }
+
+    #[test]
+    fn ignores_codes_next_to_underscores() {
+        assert_eq!(replace_code("cs_CS101", "CS101", "CS102"), None);
+    }
+
+    #[test]
+    fn returns_none_without_a_mention() {
+        assert_eq!(replace_code("nothing here", "CS101", "CS102"), None);
+    }
+
+    #[test]
+    fn returns_none_for_an_empty_code() {
+        assert_eq!(replace_code("CS101", "", "CS102"), None);
+    }
+}
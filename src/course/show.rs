@@ -0,0 +1,186 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+    fs, io,
+    path::Path,
+};
+
+use clap::Parser;
+use serde::Serialize;
+
+use super::Course;
+use crate::{
+    template::{Scope, Template},
+    Format, Settings,
+};
+
+#[derive(Parser)]
+pub struct Args {
+    /// The course code of the course (defaults to the course containing the working directory)
+    course_code: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Summary {
+    code: String,
+    #[serde(flatten)]
+    course: Course,
+    templates: Vec<String>,
+    items: BTreeMap<String, usize>,
+    disk_usage: u64,
+}
+
+pub fn run(settings: &Settings, format: Format, args: Args) -> color_eyre::Result<()> {
+    let course = Course::open_or_current(settings, args.course_code)?;
+
+    let course_templates = Template::all_in(settings, Scope::Course(course.code().to_owned()))?
+        .collect::<color_eyre::Result<Vec<_>>>()?;
+
+    let mut pluralized_names = BTreeSet::new();
+    for scope in Scope::search_order(settings, Some(course.code())) {
+        for template in Template::all_in(settings, scope)? {
+            pluralized_names.insert(template?.pluralized_name().to_owned());
+        }
+    }
+
+    let dir = course.dir(settings);
+
+    let mut items = BTreeMap::new();
+    for pluralized_name in pluralized_names {
+        let items_dir = dir.join(&pluralized_name);
+
+        if items_dir.is_dir() {
+            items.insert(pluralized_name, count_items(&items_dir)?);
+        }
+    }
+
+    let summary = Summary {
+        code: course.code().to_owned(),
+        templates: course_templates
+            .iter()
+            .map(|template| template.name().to_owned())
+            .collect(),
+        items,
+        disk_usage: dir_size(&dir)?,
+        course,
+    };
+
+    format.print(&summary, human)
+}
+
+/// The number of items in `dir`: its subdirectories, leaving out hidden ones and stray files.
+fn count_items(dir: &Path) -> io::Result<usize> {
+    let mut count = 0;
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+
+        if entry.file_type()?.is_dir() && !entry.file_name().to_string_lossy().starts_with('.') {
+            count += 1;
+        }
+    }
+
+    Ok(count)
+}
+
+fn human(summary: &Summary) -> String {
+    let course = &summary.course;
+    let mut out = String::new();
+
+    match &course.name {
+        Some(name) => writeln!(out, "{} - {name}", summary.code),
+        None => writeln!(out, "{}", summary.code),
+    }
+    .expect("writing to a string should not fail");
+
+    let fields = [
+        ("term", course.term.clone()),
+        ("section", course.section.clone()),
+        ("credits", course.credits.map(|credits| credits.to_string())),
+        ("instructors", join(&course.instructors)),
+        ("tas", join(&course.tas)),
+        ("website", course.website.clone()),
+        ("lms", course.lms.clone()),
+        ("color", course.color.clone()),
+    ]
+    .into_iter()
+    .filter_map(|(key, value)| value.map(|value| (key.to_owned(), value)))
+    .chain(course.extra.iter().map(|(key, value)| {
+        let value = serde_yaml::to_string(value).unwrap_or_default();
+        (key.clone(), value.trim_end().to_owned())
+    }));
+
+    for (key, value) in fields {
+        writeln!(out, "  {key}: {value}").expect("writing to a string should not fail");
+    }
+
+    out.push_str("\ntemplates:\n");
+    if summary.templates.is_empty() {
+        out.push_str("  (none)\n");
+    }
+    for template in &summary.templates {
+        writeln!(out, "  {template}").expect("writing to a string should not fail");
+    }
+
+    out.push_str("\nitems:\n");
+    if summary.items.is_empty() {
+        out.push_str("  (none)\n");
+    }
+    for (pluralized_name, count) in &summary.items {
+        writeln!(out, "  {pluralized_name}: {count}").expect("writing to a string should not fail");
+    }
+
+    writeln!(out, "\ndisk usage: {}", format_size(summary.disk_usage))
+        .expect("writing to a string should not fail");
+
+    out
+}
+
+fn join(values: &[String]) -> Option<String> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(values.join(", "))
+    }
+}
+
+pub fn dir_size(path: &Path) -> io::Result<u64> {
+    let mut size = 0;
+
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+
+        if file_type.is_dir() {
+            size += dir_size(&entry.path())?;
+        } else if file_type.is_file() {
+            size += entry.metadata()?.len();
+        }
+    }
+
+    Ok(size)
+}
+
+#[allow(clippy::cast_precision_loss)]
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = UNITS[0];
+
+    for next in &UNITS[1..] {
+        if size < 1024.0 {
+            break;
+        }
+
+        size /= 1024.0;
+        unit = next;
+    }
+
+    format!("{size:.1} {unit}")
+}
@@ -1,38 +1,76 @@
-use std::{fs, path::PathBuf};
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
 
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 
-use crate::{Error, Settings};
+use crate::{gpa::Outcome, grade::GradeComponent, schedule::Meeting, Error, Settings, Term};
 
 pub static COURSE_YAML: Lazy<PathBuf> = Lazy::new(|| PathBuf::from("course.yml"));
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Default)]
 #[serde(rename_all = "kebab-case")]
 pub struct Course {
     #[serde(skip)]
     code: String,
-    name: Option<String>,
-}
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 
-impl Course {
-    pub fn new(settings: &Settings, code: String, name: String) -> color_eyre::Result<Self> {
-        let course = Self {
-            code,
-            name: Some(name),
-        };
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub instructors: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tas: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub term: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credits: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub section: Option<String>,
 
-        course.create(settings)?;
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lms: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
 
-        Ok(course)
-    }
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub meetings: Vec<Meeting>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub grades: Vec<GradeComponent>,
+    /// The final percentage, overriding the one computed from `grades`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub final_grade: Option<f64>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub outcome: Outcome,
 
-    pub fn from_code(settings: &Settings, code: String) -> color_eyre::Result<Self> {
-        let course = Self { code, name: None };
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: BTreeMap<String, serde_yaml::Value>,
+    /// Context values for templates rendered into this course, taking precedence over the
+    /// settings file's.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub defaults: BTreeMap<String, BTreeMap<String, serde_yaml::Value>>,
 
-        course.create(settings)?;
+    /// Overrides of the `profile` from the settings file for this course.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profile: BTreeMap<String, serde_yaml::Value>,
 
-        Ok(course)
+    /// Fields which this version of unii does not know about, kept so they survive a rewrite.
+    #[serde(flatten)]
+    unknown: BTreeMap<String, serde_yaml::Value>,
+}
+
+impl Course {
+    /// A course with no metadata, which has not been created on disk yet.
+    pub fn new(code: String) -> Self {
+        Self {
+            code,
+            ..Self::default()
+        }
     }
 
     pub fn create(&self, settings: &Settings) -> color_eyre::Result<()> {
@@ -60,12 +98,60 @@ impl Course {
             return Ok(None);
         }
 
-        let yaml = fs::read_to_string(settings.course_yaml_path(code))?;
+        Self::read(code, &settings.course_yaml_path(code)).map(Some)
+    }
+
+    pub fn open_archived(
+        settings: &Settings,
+        term: &str,
+        code: &str,
+    ) -> color_eyre::Result<Option<Self>> {
+        let dir = settings.archived_course_dir(term, code);
+
+        if !dir.exists() {
+            return Ok(None);
+        }
+
+        Self::read(code, &dir.join(".unii").join(&*COURSE_YAML)).map(Some)
+    }
+
+    /// The course whose directory contains the working directory, if any.
+    pub fn current(settings: &Settings) -> color_eyre::Result<Option<Self>> {
+        let Ok(root) = settings.path.canonicalize() else {
+            return Ok(None);
+        };
+
+        let cwd = env::current_dir()?.canonicalize()?;
+
+        let Some(dir) = cwd.ancestors().find(|dir| {
+            dir.parent() == Some(&root) && dir.join(".unii").join(&*COURSE_YAML).is_file()
+        }) else {
+            return Ok(None);
+        };
+
+        let code = dir
+            .file_name()
+            .expect("course directory should have a name")
+            .to_string_lossy();
+
+        Self::open(settings, &code)
+    }
+
+    /// Open the course with `code`, or the current course if no code is given.
+    pub fn open_or_current(settings: &Settings, code: Option<String>) -> color_eyre::Result<Self> {
+        match code {
+            Some(code) => Ok(Self::open(settings, &code)?.ok_or(Error::CourseDoesNotExist(code))?),
+            None => Ok(Self::current(settings)?.ok_or(Error::CourseCodeMissing)?),
+        }
+    }
+
+    fn read(code: &str, yaml_path: &Path) -> color_eyre::Result<Self> {
+        let yaml = fs::read_to_string(yaml_path)?;
 
-        Ok(Some(Self {
+        Ok(Self {
             code: code.to_string(),
             ..serde_yaml::from_str(&yaml)?
-        }))
+        })
     }
 
     pub fn all(
@@ -94,6 +180,137 @@ impl Course {
         }))
     }
 
+    /// All courses which are not archived and are in the current term (or have no term).
+    pub fn active(settings: &Settings) -> color_eyre::Result<Vec<Self>> {
+        let current = Term::current(settings)?;
+
+        let mut courses = Self::all(settings)?
+            .filter(|course| {
+                course.as_ref().map_or(true, |course| {
+                    course
+                        .term
+                        .as_ref()
+                        .zip(current.as_ref())
+                        .map_or(true, |(course_term, current)| course_term == current.name())
+                })
+            })
+            .collect::<color_eyre::Result<Vec<_>>>()?;
+
+        courses.sort_by(|a, b| a.code.cmp(&b.code));
+
+        Ok(courses)
+    }
+
+    /// Move the course to a new code.
+    pub fn rename(&mut self, settings: &Settings, code: String) -> color_eyre::Result<()> {
+        let dir = settings.course_dir(&code);
+
+        if dir.exists() {
+            return Err(Error::CourseAlreadyExists(code).into());
+        }
+
+        fs::rename(self.dir(settings), dir)?;
+
+        self.code = code;
+
+        Ok(())
+    }
+
+    /// All archived courses, along with the term they are archived under.
+    pub fn archived(settings: &Settings) -> color_eyre::Result<Vec<(String, Self)>> {
+        let archive_dir = settings.archive_dir();
+
+        if !archive_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut courses = Vec::new();
+
+        for term_entry in fs::read_dir(archive_dir)? {
+            let term_path = term_entry?.path();
+
+            if !term_path.is_dir() {
+                continue;
+            }
+
+            let Some(term) = term_path.file_name().map(|term| term.to_string_lossy()) else {
+                continue;
+            };
+
+            for entry in fs::read_dir(&term_path)? {
+                let path = entry?.path();
+
+                let Some(code) = path.file_name().map(|code| code.to_string_lossy()) else {
+                    continue;
+                };
+
+                if let Some(course) = Self::open_archived(settings, &term, &code)? {
+                    courses.push((term.to_string(), course));
+                }
+            }
+        }
+
+        courses.sort_by(|(a_term, a), (b_term, b)| (a_term, &a.code).cmp(&(b_term, &b.code)));
+
+        Ok(courses)
+    }
+
+    pub fn archive(&self, settings: &Settings, term: &str) -> color_eyre::Result<()> {
+        let archived_dir = settings.archived_course_dir(term, &self.code);
+
+        if archived_dir.exists() {
+            Err(Error::CourseAlreadyArchived(
+                self.code.clone(),
+                term.to_owned(),
+            ))?;
+        }
+
+        fs::create_dir_all(settings.archive_term_dir(term))?;
+        fs::rename(self.dir(settings), archived_dir)?;
+
+        Ok(())
+    }
+
+    /// Restore an archived course, returning the term it was archived under.
+    ///
+    /// If `term` is not given, the course must be archived under exactly one term.
+    pub fn unarchive(
+        settings: &Settings,
+        code: &str,
+        term: Option<&str>,
+    ) -> color_eyre::Result<(String, Self)> {
+        if settings.course_dir(code).exists() {
+            Err(Error::CourseAlreadyExists(code.to_owned()))?;
+        }
+
+        let mut candidates =
+            Self::archived(settings)?
+                .into_iter()
+                .filter(|(archived_term, course)| {
+                    course.code == code && term.map_or(true, |term| term == archived_term)
+                });
+
+        let (term, course) = candidates
+            .next()
+            .ok_or_else(|| Error::CourseNotArchived(code.to_owned()))?;
+
+        if candidates.next().is_some() {
+            Err(Error::CourseArchivedInMultipleTerms(code.to_owned()))?;
+        }
+
+        fs::rename(
+            settings.archived_course_dir(&term, code),
+            course.dir(settings),
+        )?;
+
+        let term_dir = settings.archive_term_dir(&term);
+        if fs::read_dir(&term_dir)?.next().is_none() {
+            fs::remove_dir(term_dir)?;
+        }
+
+        Ok((term, course))
+    }
+
     pub fn dir(&self, settings: &Settings) -> PathBuf {
         settings.course_dir(&self.code)
     }
@@ -102,10 +319,6 @@ impl Course {
         settings.course_unii_dir(&self.code)
     }
 
-    pub fn template_dir(&self, settings: &Settings) -> PathBuf {
-        settings.course_template_dir(&self.code)
-    }
-
     pub fn yaml_path(&self, settings: &Settings) -> PathBuf {
         settings.course_yaml_path(&self.code)
     }
@@ -114,3 +327,35 @@ impl Course {
         &self.code
     }
 }
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Course;
+
+    #[test]
+    fn keeps_unknown_fields() {
+        let course: Course = serde_yaml::from_str(
+            "
+            name: Intro to Programming
+            credits: 0.5
+            textbook: Think Python
+            office-hours:
+              day: mon
+            ",
+        )
+        .expect("course should parse");
+
+        assert_eq!(course.name.as_deref(), Some("Intro to Programming"));
+
+        let yaml = serde_yaml::to_string(&course).expect("course should serialize");
+        let value: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("yaml should parse");
+
+        assert_eq!(value["textbook"].as_str(), Some("Think Python"));
+        assert_eq!(value["office-hours"]["day"].as_str(), Some("mon"));
+        assert_eq!(value["credits"].as_f64(), Some(0.5));
+    }
+}
@@ -0,0 +1,22 @@
+use clap::Parser;
+
+use super::Course;
+use crate::Settings;
+
+#[derive(Parser)]
+pub struct Args {
+    /// The course code of the course
+    course_code: String,
+
+    /// The term the course is archived under
+    #[clap(short, long)]
+    term: Option<String>,
+}
+
+pub fn run(settings: &Settings, Args { course_code, term }: Args) -> color_eyre::Result<()> {
+    let (term, course) = Course::unarchive(settings, &course_code, term.as_deref())?;
+
+    println!("Restored course: {} ({term})", course.code());
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use chrono::NaiveDateTime;
+use clap::Parser;
+
+use super::{types::parse_datetime, Deadline, Status};
+use crate::{Course, Error, Settings};
+
+#[derive(Parser)]
+#[clap(allow_missing_positional = true)]
+pub struct Args {
+    /// The course code of the course (defaults to the course containing the working directory)
+    course_code: Option<String>,
+
+    /// The title of the deadline
+    title: String,
+
+    /// When the deadline is due (YYYY-MM-DD [HH:MM], today, tomorrow, a weekday or +Nd)
+    #[clap(short, long, value_parser = parse_datetime)]
+    at: NaiveDateTime,
+
+    /// How much the deadline is worth towards the final grade (in percent)
+    #[clap(short, long)]
+    weight: Option<f64>,
+}
+
+pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
+    let course = Course::open_or_current(settings, args.course_code)?;
+
+    let mut deadlines = Deadline::all_in(settings, &course)?;
+
+    if deadlines
+        .iter()
+        .any(|deadline| deadline.is_titled(&args.title))
+    {
+        Err(Error::DeadlineAlreadyExists(
+            course.code().to_owned(),
+            args.title.clone(),
+        ))?;
+    }
+
+    deadlines.push(Deadline {
+        title: args.title,
+        due: args.at,
+        weight: args.weight,
+        status: Status::Pending,
+    });
+    deadlines.sort_by_key(|deadline| deadline.due);
+
+    Deadline::write_all(settings, &course, &deadlines)?;
+
+    println!("Added deadline to course: {}", course.code());
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use clap::Parser;
+
+use super::{Deadline, Status};
+use crate::{Course, Error, Settings};
+
+#[derive(Parser)]
+#[clap(allow_missing_positional = true)]
+pub struct Args {
+    /// The course code of the course (defaults to the course containing the working directory)
+    course_code: Option<String>,
+
+    /// The title of the deadline
+    title: String,
+
+    /// Mark the deadline as pending again instead
+    #[clap(short, long)]
+    undo: bool,
+}
+
+pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
+    let course = Course::open_or_current(settings, args.course_code)?;
+
+    let mut deadlines = Deadline::all_in(settings, &course)?;
+
+    let deadline = deadlines
+        .iter_mut()
+        .find(|deadline| deadline.is_titled(&args.title))
+        .ok_or_else(|| Error::DeadlineDoesNotExist(course.code().to_owned(), args.title))?;
+
+    deadline.status = if args.undo {
+        Status::Pending
+    } else {
+        Status::Done
+    };
+
+    println!(
+        "Marked deadline as {}: {}",
+        if args.undo { "pending" } else { "done" },
+        deadline.title
+    );
+
+    Deadline::write_all(settings, &course, &deadlines)?;
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use std::io::{self, IsTerminal};
+
+use chrono::{Duration, Local};
+use clap::Parser;
+use serde::Serialize;
+
+use super::{types::relative, Deadline, Status};
+use crate::{format::Table, Course, Error, Format, Settings};
+
+#[derive(Parser)]
+#[group(skip)]
+pub struct Args {
+    /// Only list deadlines of this course (defaults to the course containing the working
+    /// directory, if any)
+    #[clap(short, long, aliases = ["course", "code"])]
+    course_code: Option<String>,
+
+    /// Only list deadlines due within this many days
+    #[clap(short, long, name = "DAYS")]
+    within: Option<i64>,
+
+    /// Include deadlines which are done
+    #[clap(short, long)]
+    all: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Listing {
+    course: String,
+    #[serde(flatten)]
+    deadline: Deadline,
+}
+
+pub fn run(settings: &Settings, format: Format, args: Args) -> color_eyre::Result<()> {
+    let courses = match args.course_code {
+        Some(code) => {
+            vec![Course::open(settings, &code)?.ok_or(Error::CourseDoesNotExist(code))?]
+        }
+        None => match Course::current(settings)? {
+            Some(course) => vec![course],
+            None => Course::all(settings)?.collect::<color_eyre::Result<_>>()?,
+        },
+    };
+
+    let now = Local::now().naive_local();
+
+    let mut listings = Vec::new();
+    for course in courses {
+        for deadline in Deadline::all_in(settings, &course)? {
+            if !args.all && deadline.status == Status::Done {
+                continue;
+            }
+
+            if args
+                .within
+                .is_some_and(|days| deadline.due > now + Duration::days(days))
+            {
+                continue;
+            }
+
+            listings.push(Listing {
+                course: course.code().to_owned(),
+                deadline,
+            });
+        }
+    }
+
+    listings.sort_by_key(|listing| listing.deadline.due);
+
+    let color = io::stdout().is_terminal();
+
+    format.print(&listings, |listings| {
+        let mut table = Table::new(vec!["due", "when", "course", "title", "weight", "status"]);
+
+        for listing in listings {
+            let deadline = &listing.deadline;
+            let overdue = deadline.status == Status::Pending && deadline.due < now;
+
+            let status = match deadline.status {
+                Status::Done => "done",
+                Status::Pending if overdue => "overdue",
+                Status::Pending => "pending",
+            };
+
+            table.push(vec![
+                deadline.due.format("%a %b %-d %H:%M").to_string(),
+                relative(deadline.due - now),
+                listing.course.clone(),
+                deadline.title.clone(),
+                deadline
+                    .weight
+                    .map(|weight| format!("{weight}%"))
+                    .unwrap_or_default(),
+                if overdue && color {
+                    format!("\x1b[31m{status}\x1b[0m")
+                } else {
+                    status.to_owned()
+                },
+            ]);
+        }
+
+        table.to_string()
+    })
+}
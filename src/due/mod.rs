@@ -0,0 +1,43 @@
+mod add;
+mod done;
+mod list;
+mod types;
+
+use clap::Parser;
+
+pub use self::types::{Deadline, Status};
+
+use crate::{Format, Settings};
+
+#[derive(Parser)]
+#[clap(args_conflicts_with_subcommands = true)]
+pub struct Args {
+    #[clap(subcommand)]
+    command: Option<Command>,
+
+    #[clap(flatten)]
+    list: list::Args,
+}
+
+#[derive(Parser)]
+enum Command {
+    /// Add a deadline to a course
+    #[clap(aliases = ["new", "create"])]
+    Add(add::Args),
+
+    /// List upcoming deadlines (the default)
+    #[clap(aliases = ["ls"])]
+    List(list::Args),
+
+    /// Mark a deadline as done
+    Done(done::Args),
+}
+
+pub fn run(settings: &Settings, format: Format, args: Args) -> color_eyre::Result<()> {
+    match args.command {
+        Some(Command::Add(args)) => add::run(settings, args),
+        Some(Command::List(args)) => list::run(settings, format, args),
+        Some(Command::Done(args)) => done::run(settings, args),
+        None => list::run(settings, format, args.list),
+    }
+}
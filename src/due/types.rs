@@ -0,0 +1,238 @@
+use std::fs;
+
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::{Course, Settings};
+
+/// A deadline of a course, such as an assignment.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct Deadline {
+    pub title: String,
+    pub due: NaiveDateTime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+    #[serde(default)]
+    pub status: Status,
+}
+
+#[derive(Serialize, Deserialize, ValueEnum, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Status {
+    #[default]
+    Pending,
+    Done,
+}
+
+impl Deadline {
+    /// Whether the deadline is called `title`, ignoring case.
+    pub fn is_titled(&self, title: &str) -> bool {
+        self.title.eq_ignore_ascii_case(title)
+    }
+
+    /// The deadlines stored in `course`.
+    pub fn all_in(settings: &Settings, course: &Course) -> color_eyre::Result<Vec<Self>> {
+        let path = settings.course_deadlines_path(course.code());
+
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let yaml = fs::read_to_string(path)?;
+
+        Ok(serde_yaml::from_str(&yaml)?)
+    }
+
+    /// Replace the deadlines stored in `course`.
+    pub fn write_all(
+        settings: &Settings,
+        course: &Course,
+        deadlines: &[Self],
+    ) -> color_eyre::Result<()> {
+        fs::write(
+            settings.course_deadlines_path(course.code()),
+            serde_yaml::to_string(deadlines)?,
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Parse a date and time written as `YYYY-MM-DD HH:MM`, or a date alone (due at 23:59).
+///
+/// The date may also be `today`, `tomorrow`, a weekday (the next one after today) or `+Nd` /
+/// `+Nw` (days or weeks from today), optionally followed by `HH:MM`.
+pub fn parse_datetime(s: &str) -> Result<NaiveDateTime, String> {
+    parse_datetime_from(s, Local::now().date_naive())
+}
+
+fn parse_datetime_from(s: &str, today: NaiveDate) -> Result<NaiveDateTime, String> {
+    let s = s.trim();
+
+    if let Some(datetime) = [
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%dT%H:%M:%S",
+    ]
+    .into_iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(s, format).ok())
+    {
+        return Ok(datetime);
+    }
+
+    let invalid = || {
+        format!(
+            "invalid date: {s} (expected YYYY-MM-DD [HH:MM], today, tomorrow, a weekday or +Nd)"
+        )
+    };
+
+    let (day, time) = match s.split_once(char::is_whitespace) {
+        Some((day, time)) => (
+            day,
+            NaiveTime::parse_from_str(time.trim(), "%H:%M").map_err(|_| invalid())?,
+        ),
+        None => (
+            s,
+            NaiveTime::from_hms_opt(23, 59, 0).expect("time should be valid"),
+        ),
+    };
+
+    let date = match day.to_lowercase().as_str() {
+        "today" => today,
+        "tomorrow" => today + Duration::days(1),
+        offset if offset.starts_with('+') => {
+            let offset = &offset[1..];
+            let (amount, days_per_unit) = if let Some(amount) = offset.strip_suffix('d') {
+                (amount, 1)
+            } else if let Some(amount) = offset.strip_suffix('w') {
+                (amount, 7)
+            } else {
+                return Err(invalid());
+            };
+            let amount = amount.parse::<u16>().map_err(|_| invalid())?;
+
+            today + Duration::days(i64::from(amount) * days_per_unit)
+        }
+        day => match day.parse::<Weekday>() {
+            Ok(weekday) => {
+                let ahead = (7 + weekday.num_days_from_monday()
+                    - today.weekday().num_days_from_monday()
+                    - 1)
+                    % 7
+                    + 1;
+
+                today + Duration::days(ahead.into())
+            }
+            Err(_) => NaiveDate::parse_from_str(day, "%Y-%m-%d").map_err(|_| invalid())?,
+        },
+    };
+
+    Ok(date.and_time(time))
+}
+
+/// Describe how far away `duration` is, e.g. `in 2d` or `3h ago`.
+pub fn relative(duration: Duration) -> String {
+    let abs = if duration < Duration::zero() {
+        -duration
+    } else {
+        duration
+    };
+
+    let amount = if abs.num_days() > 0 {
+        format!("{}d", abs.num_days())
+    } else if abs.num_hours() > 0 {
+        format!("{}h", abs.num_hours())
+    } else {
+        format!("{}m", abs.num_minutes())
+    };
+
+    if duration < Duration::zero() {
+        format!("{amount} ago")
+    } else {
+        format!("in {amount}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, NaiveDate, NaiveDateTime};
+
+    use super::{parse_datetime_from, relative};
+
+    /// A Wednesday.
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2023, 9, 6).expect("date should be valid")
+    }
+
+    fn at(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(|date| date.and_hms_opt(hour, minute, 0))
+            .expect("datetime should be valid")
+    }
+
+    fn parse(s: &str) -> Option<NaiveDateTime> {
+        parse_datetime_from(s, today()).ok()
+    }
+
+    #[test]
+    fn today_and_tomorrow() {
+        assert_eq!(parse("today"), Some(at(2023, 9, 6, 23, 59)));
+        assert_eq!(parse("Tomorrow"), Some(at(2023, 9, 7, 23, 59)));
+        assert_eq!(parse("tomorrow 09:30"), Some(at(2023, 9, 7, 9, 30)));
+    }
+
+    #[test]
+    fn weekdays_are_the_next_one_after_today() {
+        assert_eq!(parse("fri"), Some(at(2023, 9, 8, 23, 59)));
+        assert_eq!(parse("Monday 10:00"), Some(at(2023, 9, 11, 10, 0)));
+        assert_eq!(parse("wed"), Some(at(2023, 9, 13, 23, 59)));
+    }
+
+    #[test]
+    fn offsets() {
+        assert_eq!(parse("+3d"), Some(at(2023, 9, 9, 23, 59)));
+        assert_eq!(parse("+0d"), Some(at(2023, 9, 6, 23, 59)));
+        assert_eq!(parse("+2w 12:00"), Some(at(2023, 9, 20, 12, 0)));
+    }
+
+    #[test]
+    fn plain_dates() {
+        assert_eq!(parse("2023-10-01"), Some(at(2023, 10, 1, 23, 59)));
+    }
+
+    #[test]
+    fn dates_with_times() {
+        assert_eq!(parse("2023-10-01 14:30"), Some(at(2023, 10, 1, 14, 30)));
+        assert_eq!(parse("2023-10-01T14:30"), Some(at(2023, 10, 1, 14, 30)));
+        assert_eq!(
+            parse("2023-10-01 14:30:15"),
+            Some(at(2023, 10, 1, 14, 30) + Duration::seconds(15))
+        );
+    }
+
+    #[test]
+    fn rejects_anything_else() {
+        for s in [
+            "",
+            "soon",
+            "2023-13-01",
+            "2023-10-01 25:00",
+            "+d",
+            "+3y",
+            "+xd",
+            "fri noon",
+        ] {
+            assert_eq!(parse(s), None, "{s:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn describes_durations() {
+        assert_eq!(relative(Duration::days(2) + Duration::hours(3)), "in 2d");
+        assert_eq!(relative(Duration::hours(-3)), "3h ago");
+        assert_eq!(relative(Duration::minutes(5)), "in 5m");
+    }
+}
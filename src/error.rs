@@ -7,14 +7,122 @@ pub enum Error {
     #[error("course with code already exists: {0}")]
     CourseAlreadyExists(String),
 
+    /// A course code was not provided and could not be inferred
+    #[error("course code was not provided and the working directory is not inside a course")]
+    CourseCodeMissing,
+
     /// A course does not exist
     #[error("course with code does not exist: {0}")]
     CourseDoesNotExist(String),
 
+    /// A course has no term
+    #[error("course with code has no term (use `--term TERM`): {0}")]
+    CourseTermMissing(String),
+
+    /// A course is already archived under a term
+    #[error("course with code is already archived in term {1}: {0}")]
+    CourseAlreadyArchived(String, String),
+
+    /// A course is not archived
+    #[error("course with code is not archived: {0}")]
+    CourseNotArchived(String),
+
+    /// A course is archived under more than one term
+    #[error("course with code is archived in multiple terms (use `--term TERM`): {0}")]
+    CourseArchivedInMultipleTerms(String),
+
+    /// A course is not in the trash
+    #[error("course with code is not in the trash: {0}")]
+    CourseNotInTrash(String),
+
+    /// A path inside a course does not exist
+    #[error("path does not exist in course {0}: {1}")]
+    CoursePathDoesNotExist(String, String),
+
+    /// No program is configured to open a course with
+    #[error("no opener configured: {0}")]
+    OpenerMissing(&'static str),
+
+    /// The program used to open a course failed
+    #[error("opener failed: {0}")]
+    OpenerFailed(String),
+
+    /// A settings key does not exist
+    #[error("key does not exist: {0}")]
+    ConfigKeyDoesNotExist(String),
+
+    /// A settings key goes through a value which is not a mapping
+    #[error("key does not refer to a mapping: {0}")]
+    ConfigKeyNotAMapping(String),
+
+    /// A term already exists
+    #[error("term already exists: {0}")]
+    TermAlreadyExists(String),
+
+    /// A term does not exist
+    #[error("term does not exist: {0}")]
+    TermDoesNotExist(String),
+
+    /// A term ends before it starts
+    #[error("term ends before it starts: {0}")]
+    TermEndsBeforeStart(String),
+
+    /// There is no current term
+    #[error("there is no current term (use `unii term set TERM`)")]
+    CurrentTermMissing,
+
+    /// A meeting ends before it starts
+    #[error("meeting must end after it starts")]
+    MeetingEndsBeforeStart,
+
+    /// A meeting clashes with other meetings
+    #[error("meeting clashes with: {0} (use `--force` to add it anyway)")]
+    MeetingClash(String),
+
+    /// A meeting does not exist
+    #[error("course {0} has no meeting number {1}")]
+    MeetingDoesNotExist(String, usize),
+
+    /// A deadline already exists
+    #[error("course {0} already has a deadline titled: {1}")]
+    DeadlineAlreadyExists(String, String),
+
+    /// A deadline does not exist
+    #[error("course {0} has no deadline titled: {1}")]
+    DeadlineDoesNotExist(String, String),
+
+    /// A grade component already exists
+    #[error("course {0} already has a grade component named: {1}")]
+    GradeComponentAlreadyExists(String, String),
+
+    /// A grade component does not exist
+    #[error("course {0} has no grade component named: {1}")]
+    GradeComponentDoesNotExist(String, String),
+
+    /// A grade component weight is negative or not a number
+    #[error("invalid weight: {0} (expected a non-negative number)")]
+    GradeWeightInvalid(String),
+
+    /// A grading scale does not exist
+    #[error("grading scale does not exist: {0}")]
+    GradingScaleDoesNotExist(String),
+
+    /// An iCalendar file could not be read
+    #[error("invalid iCalendar file: {0}")]
+    IcsInvalid(String),
+
+    /// There is no term to import courses into
+    #[error("there is no term to import courses into (use `--term TERM`)")]
+    ImportTermMissing,
+
     /// A template already exists
     #[error("template already exists: {0}")]
     TemplateAlreadyExists(String),
 
+    /// A template is not declared correctly
+    #[error("invalid template {0}: {1}")]
+    TemplateInvalid(String, String),
+
     /// A template does not exist
     #[error("template does not exist: {}{1}", .0.clone().map(|s| format!("{s}:")).unwrap_or_default())]
     TemplateDoesNotExist(Option<String>, String),
@@ -23,19 +131,31 @@ pub enum Error {
     #[error("template context parameter does not exist: {0}")]
     TemplateContextParameterDoesNotExist(String),
 
+    /// A template context parameter has an invalid value
+    #[error("invalid value for template context parameter {0}: {1}")]
+    TemplateContextParameterInvalid(String, String),
+
+    /// A template context parameter was not given and has no default
+    #[error("missing value for template context parameter: {0}")]
+    TemplateContextParameterMissing(String),
+
     /// A template command is empty
     #[error("template command is empty")]
     TemplateCommandIsEmpty,
 
     /// A template command failed
-    #[error("template command failed: {0}\n{1}")]
+    #[error("template command failed ({1}): {0}")]
     TemplateCommandFailed(String, String),
 
+    /// A template step would run outside the rendered item
+    #[error("template step directory must be inside the rendered item: {0}")]
+    TemplateStepDirOutsideItem(String),
+
     /// A render already exists
     #[error("render already exists: {0}")]
     RenderAlreadyExists(String),
 
     /// A course code was not provided
-    #[error("course code was not provided to render template into (use `CODE:TEMPLATE` or `--course CODE`)")]
+    #[error("course code was not provided to render template into (use `CODE:TEMPLATE`, `--course CODE` or run inside a course)")]
     TemplateCourseCodeMissing,
 }
@@ -0,0 +1,193 @@
+use std::{fs, path::PathBuf};
+
+use chrono::{Datelike, Duration, Utc};
+use clap::Parser;
+use heck::ToKebabCase;
+
+use crate::{
+    due::{Deadline, Status},
+    ics::{self, Writer},
+    schedule::Meeting,
+    term::week_start,
+    Course, Settings, Term,
+};
+
+#[derive(Parser)]
+pub struct Args {
+    /// The file to write the calendar to (defaults to stdout)
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+
+    /// How long before a deadline its alarm goes off, in hours
+    #[clap(long, default_value_t = 24)]
+    alarm: u32,
+}
+
+pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
+    let mut calendar = Writer::new();
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+
+    for course in Course::all(settings)? {
+        let course = course?;
+        let summary_prefix = course.name.as_ref().map_or_else(
+            || course.code().to_owned(),
+            |name| format!("{} {name}", course.code()),
+        );
+
+        let term = course
+            .term
+            .as_ref()
+            .map(|term| Term::open(settings, term))
+            .transpose()?
+            .flatten();
+
+        match &term {
+            Some(term) => {
+                for meeting in &course.meetings {
+                    write_meeting(
+                        &mut calendar,
+                        &stamp,
+                        &course,
+                        term,
+                        meeting,
+                        &summary_prefix,
+                    );
+                }
+            }
+            None if !course.meetings.is_empty() => {
+                eprintln!(
+                    "Skipping meetings of {}: course has no term to bound them",
+                    course.code()
+                );
+            }
+            None => {}
+        }
+
+        for deadline in Deadline::all_in(settings, &course)? {
+            write_deadline(&mut calendar, &stamp, &course, &deadline, args.alarm);
+        }
+    }
+
+    let calendar = calendar.finish();
+
+    match args.output {
+        Some(path) => {
+            fs::write(&path, calendar)?;
+
+            println!("Wrote calendar to: {}", path.display());
+        }
+        None => print!("{calendar}"),
+    }
+
+    Ok(())
+}
+
+/// Write `meeting` as an event repeating weekly over `term`, except during its breaks.
+///
+/// The UID only depends on the meeting's kind, day, start and term, so that adding or removing
+/// another meeting does not change it.
+fn write_meeting(
+    calendar: &mut Writer,
+    stamp: &str,
+    course: &Course,
+    term: &Term,
+    meeting: &Meeting,
+    summary_prefix: &str,
+) {
+    let offset =
+        (7 + meeting.day.num_days_from_monday() - term.start.weekday().num_days_from_monday()) % 7;
+    let first = term.start + Duration::days(offset.into());
+
+    if first > term.end {
+        return;
+    }
+
+    calendar.property("BEGIN", "VEVENT");
+    calendar.text(
+        "UID",
+        &format!(
+            "{}-meeting-{}-{}-{}-{}@unii",
+            course.code(),
+            meeting.kind.to_string().to_kebab_case(),
+            meeting.day.to_string().to_lowercase(),
+            meeting.start.format("%H%M"),
+            term.name()
+        ),
+    );
+    calendar.property("DTSTAMP", stamp);
+    calendar.datetime("DTSTART", first.and_time(meeting.start));
+    calendar.datetime("DTEND", first.and_time(meeting.end));
+    calendar.property(
+        "RRULE",
+        &format!("FREQ=WEEKLY;UNTIL={}T235959", ics::format_date(term.end)),
+    );
+
+    for &break_day in &term.breaks {
+        let date =
+            week_start(break_day) + Duration::days(meeting.day.num_days_from_monday().into());
+
+        if date >= first && term.contains(date) {
+            calendar.datetime("EXDATE", date.and_time(meeting.start));
+        }
+    }
+
+    calendar.text("SUMMARY", &format!("{summary_prefix} ({})", meeting.kind));
+
+    let place = meeting.place();
+    if !place.is_empty() {
+        calendar.text("LOCATION", &place);
+    }
+
+    calendar.property("END", "VEVENT");
+}
+
+/// Write `deadline` as an event with an alarm `alarm` hours before it is due.
+///
+/// Done deadlines are kept but get no alarm and are marked in their summary, since
+/// `STATUS:COMPLETED` is only defined for to-dos, not events.
+///
+/// The UID only depends on the deadline's title and due date, so that adding or removing another
+/// deadline does not change it.
+fn write_deadline(
+    calendar: &mut Writer,
+    stamp: &str,
+    course: &Course,
+    deadline: &Deadline,
+    alarm: u32,
+) {
+    let done = deadline.status == Status::Done;
+    let summary = format!(
+        "{}: {}{}",
+        course.code(),
+        deadline.title,
+        if done { " (done)" } else { "" }
+    );
+
+    calendar.property("BEGIN", "VEVENT");
+    calendar.text(
+        "UID",
+        &format!(
+            "{}-deadline-{}-{}@unii",
+            course.code(),
+            deadline.title.to_kebab_case(),
+            ics::format_datetime(deadline.due)
+        ),
+    );
+    calendar.property("DTSTAMP", stamp);
+    calendar.datetime("DTSTART", deadline.due);
+    calendar.datetime("DTEND", deadline.due);
+    calendar.text("SUMMARY", &summary);
+    if let Some(weight) = deadline.weight {
+        calendar.text("DESCRIPTION", &format!("Worth {weight}%"));
+    }
+
+    if !done {
+        calendar.property("BEGIN", "VALARM");
+        calendar.property("ACTION", "DISPLAY");
+        calendar.text("DESCRIPTION", &summary);
+        calendar.property("TRIGGER", &format!("-PT{alarm}H"));
+        calendar.property("END", "VALARM");
+    }
+
+    calendar.property("END", "VEVENT");
+}
@@ -0,0 +1,89 @@
+use std::fmt::{self, Display};
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// How listings are written to stdout.
+#[derive(Clone, Copy, Default, ValueEnum)]
+pub enum Format {
+    /// An aligned table (or summary) for people to read
+    #[default]
+    Table,
+
+    /// JSON
+    Json,
+
+    /// YAML
+    Yaml,
+}
+
+impl Format {
+    /// Print `value` as JSON or YAML, or print the output of `human` in table mode.
+    pub fn print<T: Serialize>(
+        self,
+        value: &T,
+        human: impl FnOnce(&T) -> String,
+    ) -> color_eyre::Result<()> {
+        match self {
+            Self::Table => print!("{}", human(value)),
+            Self::Json => println!("{}", serde_json::to_string_pretty(value)?),
+            Self::Yaml => print!("{}", serde_yaml::to_string(value)?),
+        }
+
+        Ok(())
+    }
+}
+
+/// A table whose columns are padded to line up.
+pub struct Table {
+    headers: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub const fn new(headers: Vec<&'static str>) -> Self {
+        Self {
+            headers,
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut widths = self
+            .headers
+            .iter()
+            .map(|header| header.len())
+            .collect::<Vec<_>>();
+
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let headers = self.headers.iter().map(|header| header.to_uppercase());
+        let mut write_row = |cells: &mut dyn Iterator<Item = String>| {
+            let line = cells
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:width$}"))
+                .collect::<Vec<_>>()
+                .join("  ");
+
+            writeln!(f, "{}", line.trim_end())
+        };
+
+        write_row(&mut headers.into_iter())?;
+
+        for row in &self.rows {
+            write_row(&mut row.iter().cloned())?;
+        }
+
+        Ok(())
+    }
+}
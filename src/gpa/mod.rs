@@ -0,0 +1,315 @@
+mod types;
+
+use std::{collections::BTreeMap, fmt::Write};
+
+use clap::Parser;
+use serde::Serialize;
+
+pub use self::types::{
+    default_grading_scales, grade_points, GradingScale, Outcome, DEFAULT_GRADING_SCALE,
+};
+
+use crate::{
+    format::Table,
+    grade::{GradeComponent, Standing},
+    Course, Error, Format, Settings, Term,
+};
+
+#[derive(Parser)]
+pub struct Args {
+    /// The grading scale to use (defaults to the `grading-scale` setting)
+    #[clap(short, long)]
+    scale: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Report {
+    scale: String,
+    terms: Vec<TermReport>,
+    cumulative: Option<f64>,
+    credits_earned: f64,
+
+    /// Graded courses left out of the GPA because they have no credits set.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    missing_credits: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct TermReport {
+    term: Option<String>,
+    gpa: Option<f64>,
+    courses: Vec<CourseReport>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct CourseReport {
+    code: String,
+    credits: Option<f64>,
+    outcome: Outcome,
+    percent: Option<f64>,
+    letter: Option<String>,
+    points: Option<f64>,
+}
+
+impl CourseReport {
+    /// Whether the course counts towards GPA.
+    ///
+    /// Courses without credits are left out rather than given a made-up weight.
+    fn counts(&self) -> bool {
+        self.outcome == Outcome::Graded && self.points.is_some() && self.credits.is_some()
+    }
+}
+
+pub fn run(settings: &Settings, format: Format, args: Args) -> color_eyre::Result<()> {
+    let scale_name = args
+        .scale
+        .or_else(|| settings.grading_scale.clone())
+        .unwrap_or_else(|| DEFAULT_GRADING_SCALE.to_owned());
+
+    let scale = settings
+        .grading_scale(&scale_name)
+        .ok_or_else(|| Error::GradingScaleDoesNotExist(scale_name.clone()))?;
+
+    let courses = Course::all(settings)?
+        .map(|course| course.map(|course| (course.term.clone(), course)))
+        .chain(
+            Course::archived(settings)?
+                .into_iter()
+                .map(|(term, course)| Ok((course.term.clone().or(Some(term)), course))),
+        )
+        .collect::<color_eyre::Result<Vec<_>>>()?;
+
+    let mut by_term = BTreeMap::<Option<String>, Vec<CourseReport>>::new();
+    for (term, course) in courses {
+        let percent = course.final_grade.or_else(|| final_percent(&course.grades));
+        let graded = percent.map(|percent| grade_points(&scale, percent));
+
+        by_term.entry(term).or_default().push(CourseReport {
+            code: course.code().to_owned(),
+            credits: course.credits,
+            outcome: course.outcome,
+            percent,
+            letter: graded.as_ref().map(|(letter, _)| letter.clone()),
+            points: graded.map(|(_, points)| points),
+        });
+    }
+
+    let mut terms = by_term
+        .into_iter()
+        .map(|(term, mut courses)| {
+            courses.sort_by(|a, b| a.code.cmp(&b.code));
+
+            TermReport {
+                gpa: gpa(&courses),
+                term,
+                courses,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let starts = Term::all(settings)?
+        .into_iter()
+        .map(|term| (term.name().to_owned(), term.start))
+        .collect::<BTreeMap<_, _>>();
+    terms.sort_by_key(|report| {
+        (
+            report
+                .term
+                .as_ref()
+                .and_then(|term| starts.get(term))
+                .copied(),
+            report.term.clone(),
+        )
+    });
+
+    let all_courses = terms
+        .iter()
+        .flat_map(|term| &term.courses)
+        .collect::<Vec<_>>();
+
+    let missing_credits = all_courses
+        .iter()
+        .filter(|course| course.outcome == Outcome::Graded && course.credits.is_none())
+        .map(|course| course.code.clone())
+        .collect();
+
+    let report = Report {
+        scale: scale_name,
+        missing_credits,
+        cumulative: gpa(all_courses.iter().copied()),
+        credits_earned: all_courses
+            .iter()
+            .filter(|course| match course.outcome {
+                Outcome::Pass => true,
+                Outcome::Graded => course.points.is_some_and(|points| points > 0.0),
+                Outcome::Fail | Outcome::Withdrawn => false,
+            })
+            .filter_map(|course| course.credits)
+            .sum(),
+        terms,
+    };
+
+    format.print(&report, human)
+}
+
+/// The final percentage of a course without a `final-grade`, once every component is graded.
+///
+/// Courses still in progress have none, so that partial marks are not counted as final.
+fn final_percent(grades: &[GradeComponent]) -> Option<f64> {
+    let standing = Standing::of(grades);
+
+    (standing.remaining_weight <= 0.0)
+        .then_some(standing.current)
+        .flatten()
+}
+
+/// The credit-weighted average of the grade points of the courses which count towards GPA.
+fn gpa<'a>(courses: impl IntoIterator<Item = &'a CourseReport>) -> Option<f64> {
+    let (credits, points) = courses
+        .into_iter()
+        .filter(|course| course.counts())
+        .filter_map(|course| course.credits.zip(course.points))
+        .fold((0.0, 0.0), |(total, earned), (credits, points)| {
+            (total + credits, credits.mul_add(points, earned))
+        });
+
+    (credits > 0.0).then(|| points / credits)
+}
+
+fn human(report: &Report) -> String {
+    let mut out = String::new();
+
+    for term in &report.terms {
+        let mut table = Table::new(vec!["course", "credits", "grade", "letter", "points"]);
+
+        for course in &term.courses {
+            table.push(vec![
+                course.code.clone(),
+                course
+                    .credits
+                    .map_or_else(|| "-".to_owned(), |credits| credits.to_string()),
+                match course.outcome {
+                    Outcome::Graded => course
+                        .percent
+                        .map_or_else(|| "-".to_owned(), |percent| format!("{percent:.1}%")),
+                    Outcome::Pass => "pass".to_owned(),
+                    Outcome::Fail => "fail".to_owned(),
+                    Outcome::Withdrawn => "withdrawn".to_owned(),
+                },
+                course.letter.clone().unwrap_or_default(),
+                course
+                    .points
+                    .filter(|_| course.counts())
+                    .map(|points| format!("{points:.2}"))
+                    .unwrap_or_default(),
+            ]);
+        }
+
+        writeln!(
+            out,
+            "{}: {}\n{table}",
+            term.term.as_deref().unwrap_or("(no term)"),
+            format_gpa(term.gpa)
+        )
+        .expect("writing to a string should not fail");
+    }
+
+    writeln!(
+        out,
+        "cumulative ({}): {}\ncredits earned: {}",
+        report.scale,
+        format_gpa(report.cumulative),
+        report.credits_earned
+    )
+    .expect("writing to a string should not fail");
+
+    if !report.missing_credits.is_empty() {
+        writeln!(
+            out,
+            "not counted (no credits set): {}",
+            report.missing_credits.join(", ")
+        )
+        .expect("writing to a string should not fail");
+    }
+
+    out
+}
+
+fn format_gpa(gpa: Option<f64>) -> String {
+    gpa.map_or_else(|| "-".to_owned(), |gpa| format!("{gpa:.2}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{final_percent, gpa, CourseReport, Outcome};
+    use crate::grade::GradeComponent;
+
+    fn report(credits: Option<f64>, outcome: Outcome, points: Option<f64>) -> CourseReport {
+        CourseReport {
+            code: "CS101".to_owned(),
+            credits,
+            outcome,
+            percent: None,
+            letter: None,
+            points,
+        }
+    }
+
+    fn component(weight: f64, score: Option<f64>) -> GradeComponent {
+        GradeComponent {
+            name: "component".to_owned(),
+            weight,
+            score,
+        }
+    }
+
+    #[test]
+    fn weighs_by_credits() {
+        let courses = [
+            report(Some(0.5), Outcome::Graded, Some(4.0)),
+            report(Some(1.5), Outcome::Graded, Some(2.0)),
+        ];
+
+        assert_eq!(gpa(&courses), Some(2.5));
+    }
+
+    #[test]
+    fn leaves_out_courses_which_do_not_count() {
+        let courses = [
+            report(Some(1.0), Outcome::Graded, Some(3.0)),
+            report(None, Outcome::Graded, Some(0.0)),
+            report(Some(1.0), Outcome::Graded, None),
+            report(Some(1.0), Outcome::Pass, None),
+            report(Some(1.0), Outcome::Fail, None),
+            report(Some(1.0), Outcome::Withdrawn, None),
+        ];
+
+        assert_eq!(gpa(&courses), Some(3.0));
+    }
+
+    #[test]
+    fn nothing_to_count() {
+        assert_eq!(gpa(&[]), None);
+        assert_eq!(gpa(&[report(None, Outcome::Graded, Some(4.0))]), None);
+    }
+
+    #[test]
+    fn in_progress_courses_have_no_final_percent() {
+        assert_eq!(final_percent(&[]), None);
+        assert_eq!(
+            final_percent(&[component(40.0, Some(90.0)), component(60.0, None)]),
+            None
+        );
+    }
+
+    #[test]
+    fn fully_graded_courses_have_a_final_percent() {
+        assert_eq!(
+            final_percent(&[component(40.0, Some(90.0)), component(60.0, Some(80.0))]),
+            Some(84.0)
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::BTreeMap;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// One step of a grading scale: every percentage at or above `min` earns `letter` and `points`.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct ScaleStep {
+    pub min: f64,
+    pub letter: String,
+    pub points: f64,
+}
+
+/// A table mapping percentages to letters and grade points.
+///
+/// An empty scale uses the percentage itself as the grade points.
+pub type GradingScale = Vec<ScaleStep>;
+
+/// How a course finished, for the purposes of GPA.
+#[derive(Serialize, Deserialize, ValueEnum, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Outcome {
+    /// The course is graded and counts towards GPA
+    #[default]
+    Graded,
+
+    /// The course was taken pass/fail and was passed
+    Pass,
+
+    /// The course was taken pass/fail and was failed
+    Fail,
+
+    /// The course was dropped after the deadline
+    Withdrawn,
+}
+
+/// Look up the letter and grade points `percent` earns on `scale`.
+pub fn grade_points(scale: &[ScaleStep], percent: f64) -> (String, f64) {
+    if scale.is_empty() {
+        return (format!("{percent:.1}%"), percent);
+    }
+
+    scale
+        .iter()
+        .filter(|step| percent >= step.min)
+        .max_by(|a, b| a.min.total_cmp(&b.min))
+        .map_or_else(
+            || ("F".to_owned(), 0.0),
+            |step| (step.letter.clone(), step.points),
+        )
+}
+
+pub const DEFAULT_GRADING_SCALE: &str = "4.0";
+
+/// The grading scales available when none are configured.
+pub fn default_grading_scales() -> BTreeMap<String, GradingScale> {
+    fn scale(steps: &[(f64, &str, f64)]) -> GradingScale {
+        steps
+            .iter()
+            .map(|&(min, letter, points)| ScaleStep {
+                min,
+                letter: letter.to_owned(),
+                points,
+            })
+            .collect()
+    }
+
+    BTreeMap::from([
+        (
+            "4.0".to_owned(),
+            scale(&[
+                (90.0, "A", 4.0),
+                (80.0, "B", 3.0),
+                (70.0, "C", 2.0),
+                (60.0, "D", 1.0),
+                (0.0, "F", 0.0),
+            ]),
+        ),
+        (
+            "4.33".to_owned(),
+            scale(&[
+                (90.0, "A+", 4.33),
+                (85.0, "A", 4.0),
+                (80.0, "A-", 3.67),
+                (77.0, "B+", 3.33),
+                (73.0, "B", 3.0),
+                (70.0, "B-", 2.67),
+                (67.0, "C+", 2.33),
+                (63.0, "C", 2.0),
+                (60.0, "C-", 1.67),
+                (57.0, "D+", 1.33),
+                (53.0, "D", 1.0),
+                (50.0, "D-", 0.67),
+                (0.0, "F", 0.0),
+            ]),
+        ),
+        (
+            "9-point".to_owned(),
+            scale(&[
+                (90.0, "A+", 9.0),
+                (80.0, "A", 8.0),
+                (75.0, "B+", 7.0),
+                (70.0, "B", 6.0),
+                (65.0, "C+", 5.0),
+                (60.0, "C", 4.0),
+                (55.0, "D+", 3.0),
+                (50.0, "D", 2.0),
+                (40.0, "E", 1.0),
+                (0.0, "F", 0.0),
+            ]),
+        ),
+        ("percentage".to_owned(), Vec::new()),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{default_grading_scales, grade_points};
+
+    #[test]
+    fn looks_up_the_highest_step_reached() {
+        let scales = default_grading_scales();
+        let scale = &scales["4.0"];
+
+        assert_eq!(grade_points(scale, 95.0), ("A".to_owned(), 4.0));
+        assert_eq!(grade_points(scale, 90.0), ("A".to_owned(), 4.0));
+        assert_eq!(grade_points(scale, 89.9), ("B".to_owned(), 3.0));
+        assert_eq!(grade_points(scale, 0.0), ("F".to_owned(), 0.0));
+    }
+
+    #[test]
+    fn steps_may_be_in_any_order() {
+        let mut scale = default_grading_scales()
+            .remove("4.33")
+            .expect("scale should exist");
+        scale.reverse();
+
+        assert_eq!(grade_points(&scale, 86.0), ("A".to_owned(), 4.0));
+    }
+
+    #[test]
+    fn below_every_step_is_an_f() {
+        let mut scale = default_grading_scales()
+            .remove("9-point")
+            .expect("scale should exist");
+        scale.retain(|step| step.min > 0.0);
+
+        assert_eq!(grade_points(&scale, 10.0), ("F".to_owned(), 0.0));
+    }
+
+    #[test]
+    fn an_empty_scale_uses_the_percentage() {
+        assert_eq!(grade_points(&[], 87.5), ("87.5%".to_owned(), 87.5));
+    }
+}
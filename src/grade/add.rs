@@ -0,0 +1,51 @@
+use clap::Parser;
+
+use super::{
+    types::{parse_score, parse_weight},
+    GradeComponent,
+};
+use crate::{Course, Error, Settings};
+
+#[derive(Parser)]
+#[clap(allow_missing_positional = true)]
+pub struct Args {
+    /// The course code of the course (defaults to the course containing the working directory)
+    course_code: Option<String>,
+
+    /// The name of the component
+    name: String,
+
+    /// How much the component counts towards the final grade
+    #[clap(short, long, value_parser = parse_weight)]
+    weight: f64,
+
+    /// The score received (e.g. `85`, `85%` or `17/20`)
+    #[clap(short, long, value_parser = parse_score)]
+    score: Option<f64>,
+}
+
+pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
+    let mut course = Course::open_or_current(settings, args.course_code)?;
+
+    if course
+        .grades
+        .iter()
+        .any(|component| component.name.eq_ignore_ascii_case(&args.name))
+    {
+        Err(Error::GradeComponentAlreadyExists(
+            course.code().to_owned(),
+            args.name.clone(),
+        ))?;
+    }
+
+    course.grades.push(GradeComponent {
+        name: args.name,
+        weight: args.weight,
+        score: args.score,
+    });
+    course.write(settings)?;
+
+    println!("Added grade component to course: {}", course.code());
+
+    Ok(())
+}
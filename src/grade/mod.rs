@@ -0,0 +1,37 @@
+mod add;
+mod set;
+mod show;
+mod types;
+
+use clap::Parser;
+
+pub use self::types::{GradeComponent, Standing};
+
+use crate::{Format, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Parser)]
+enum Command {
+    /// Add a graded component to a course
+    #[clap(aliases = ["new", "create"])]
+    Add(add::Args),
+
+    /// Record the score received on a component
+    Set(set::Args),
+
+    /// Show the grades of a course, with best and worst possible final grades
+    Show(show::Args),
+}
+
+pub fn run(settings: &Settings, format: Format, args: Args) -> color_eyre::Result<()> {
+    match args.command {
+        Command::Add(args) => add::run(settings, args),
+        Command::Set(args) => set::run(settings, args),
+        Command::Show(args) => show::run(settings, format, args),
+    }
+}
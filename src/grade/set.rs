@@ -0,0 +1,39 @@
+use clap::Parser;
+
+use super::types::parse_score;
+use crate::{Course, Error, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    /// The course code of the course (defaults to the course containing the working directory),
+    /// followed by the name of the component
+    #[clap(num_args = 1..=2, required = true, value_name = "[COURSE_CODE] NAME")]
+    target: Vec<String>,
+
+    /// The score received (e.g. `85`, `85%` or `17/20`)
+    #[clap(value_parser = parse_score)]
+    score: f64,
+}
+
+pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
+    let mut target = args.target;
+    let name = target.pop().expect("clap should require a component name");
+
+    let mut course = Course::open_or_current(settings, target.pop())?;
+
+    let code = course.code().to_owned();
+
+    let component = course
+        .grades
+        .iter_mut()
+        .find(|component| component.name.eq_ignore_ascii_case(&name))
+        .ok_or(Error::GradeComponentDoesNotExist(code, name))?;
+
+    component.score = Some(args.score);
+
+    println!("Set score of {}: {:.1}%", component.name, args.score);
+
+    course.write(settings)?;
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use std::fmt::Write;
+
+use clap::Parser;
+use serde::Serialize;
+
+use super::{GradeComponent, Standing};
+use crate::{format::Table, Course, Format, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    /// The course code of the course (defaults to the course containing the working directory)
+    course_code: Option<String>,
+
+    /// A final grade to work out the needed score for (can be repeated)
+    #[clap(short, long)]
+    target: Vec<f64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Report {
+    code: String,
+    components: Vec<GradeComponent>,
+    #[serde(flatten)]
+    standing: Standing,
+    needed: Vec<Needed>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Needed {
+    target: f64,
+    score: Option<f64>,
+}
+
+pub fn run(settings: &Settings, format: Format, args: Args) -> color_eyre::Result<()> {
+    let course = Course::open_or_current(settings, args.course_code)?;
+
+    let standing = Standing::of(&course.grades);
+
+    let report = Report {
+        code: course.code().to_owned(),
+        needed: args
+            .target
+            .into_iter()
+            .map(|target| Needed {
+                target,
+                score: standing.needed(target),
+            })
+            .collect(),
+        components: course.grades,
+        standing,
+    };
+
+    format.print(&report, human)
+}
+
+fn human(report: &Report) -> String {
+    let percent =
+        |value: Option<f64>| value.map_or_else(|| "-".to_owned(), |value| format!("{value:.1}%"));
+
+    let mut table = Table::new(vec!["component", "weight", "score"]);
+    for component in &report.components {
+        table.push(vec![
+            component.name.clone(),
+            component.weight.to_string(),
+            percent(component.score),
+        ]);
+    }
+
+    let mut out = table.to_string();
+    let standing = &report.standing;
+
+    writeln!(
+        out,
+        "\ncurrent: {}\nworst:   {}\nbest:    {}",
+        percent(standing.current),
+        percent(standing.worst),
+        percent(standing.best)
+    )
+    .expect("writing to a string should not fail");
+
+    for needed in &report.needed {
+        match needed.score {
+            Some(score) if score > 100.0 => writeln!(
+                out,
+                "{:.1}% is out of reach (needs {score:.1}% on the rest)",
+                needed.target
+            ),
+            Some(score) if score <= 0.0 => {
+                writeln!(out, "{:.1}% is already secured", needed.target)
+            }
+            Some(score) => writeln!(
+                out,
+                "{:.1}% needs {score:.1}% on the remaining {} weight",
+                needed.target, standing.remaining_weight
+            ),
+            None => writeln!(out, "{:.1}%: nothing left to grade", needed.target),
+        }
+        .expect("writing to a string should not fail");
+    }
+
+    out
+}
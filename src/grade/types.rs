@@ -0,0 +1,224 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+/// A graded part of a course, such as an assignment or exam.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct GradeComponent {
+    pub name: String,
+
+    /// How much the component counts towards the final grade.
+    pub weight: f64,
+
+    /// The score received, as a percentage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+}
+
+/// Where a course's grade stands, given the components graded so far.
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Standing {
+    /// The weighted average of the graded components.
+    pub current: Option<f64>,
+
+    /// The final grade if every remaining component scores 0%.
+    pub worst: Option<f64>,
+
+    /// The final grade if every remaining component scores 100%.
+    pub best: Option<f64>,
+
+    /// The weight of the components which have not been graded.
+    pub remaining_weight: f64,
+
+    #[serde(skip)]
+    earned: f64,
+    #[serde(skip)]
+    total_weight: f64,
+}
+
+impl Standing {
+    pub fn of(components: &[GradeComponent]) -> Self {
+        let total_weight = components
+            .iter()
+            .map(|component| component.weight)
+            .sum::<f64>();
+
+        let (graded_weight, earned) = components
+            .iter()
+            .filter_map(|component| component.score.map(|score| (component.weight, score)))
+            .fold((0.0, 0.0), |(weight, earned), (component_weight, score)| {
+                (
+                    weight + component_weight,
+                    component_weight.mul_add(score, earned),
+                )
+            });
+
+        let percent_of = |weight: f64, earned: f64| (weight > 0.0).then(|| earned / weight);
+
+        Self {
+            current: percent_of(graded_weight, earned),
+            worst: percent_of(total_weight, earned),
+            best: percent_of(
+                total_weight,
+                (total_weight - graded_weight).mul_add(100.0, earned),
+            ),
+            remaining_weight: total_weight - graded_weight,
+            earned,
+            total_weight,
+        }
+    }
+
+    /// The average score needed on the remaining components to finish with `target`.
+    ///
+    /// Returns `None` if nothing is left to be graded.
+    pub fn needed(&self, target: f64) -> Option<f64> {
+        (self.remaining_weight > 0.0)
+            .then(|| target.mul_add(self.total_weight, -self.earned) / self.remaining_weight)
+    }
+}
+
+/// Parse a score written as a percentage (`85`, `85%`) or a fraction (`17/20`).
+///
+/// Scores above 100% are allowed for bonus marks, but negative and non-finite scores are not.
+pub fn parse_score(s: &str) -> Result<f64, String> {
+    let invalid = || format!("invalid score: {s}");
+
+    let score = if let Some((numerator, denominator)) = s.split_once('/') {
+        let numerator = numerator.trim().parse::<f64>().map_err(|_| invalid())?;
+        let denominator = denominator.trim().parse::<f64>().map_err(|_| invalid())?;
+
+        if denominator <= 0.0 || !denominator.is_finite() {
+            return Err(invalid());
+        }
+
+        numerator / denominator * 100.0
+    } else {
+        s.trim()
+            .trim_end_matches('%')
+            .parse::<f64>()
+            .map_err(|_| invalid())?
+    };
+
+    if score < 0.0 || !score.is_finite() {
+        return Err(invalid());
+    }
+
+    Ok(score)
+}
+
+/// Parse the weight of a grade component, which must be a non-negative finite number.
+pub fn parse_weight(s: &str) -> Result<f64, Error> {
+    s.trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|weight| *weight >= 0.0 && weight.is_finite())
+        .ok_or_else(|| Error::GradeWeightInvalid(s.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_score, parse_weight, GradeComponent, Standing};
+
+    fn component(weight: f64, score: Option<f64>) -> GradeComponent {
+        GradeComponent {
+            name: format!("component worth {weight}"),
+            weight,
+            score,
+        }
+    }
+
+    fn assert_close(actual: Option<f64>, expected: f64) {
+        let actual = actual.expect("value should be present");
+
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn nothing_graded() {
+        let standing = Standing::of(&[component(40.0, None), component(60.0, None)]);
+
+        assert_eq!(standing.current, None);
+        assert_close(standing.worst, 0.0);
+        assert_close(standing.best, 100.0);
+        assert_close(Some(standing.remaining_weight), 100.0);
+        assert_close(standing.needed(75.0), 75.0);
+    }
+
+    #[test]
+    fn no_components() {
+        let standing = Standing::of(&[]);
+
+        assert_eq!(standing.current, None);
+        assert_eq!(standing.worst, None);
+        assert_eq!(standing.best, None);
+        assert_eq!(standing.needed(50.0), None);
+    }
+
+    #[test]
+    fn partly_graded() {
+        let standing = Standing::of(&[component(40.0, Some(80.0)), component(60.0, None)]);
+
+        assert_close(standing.current, 80.0);
+        assert_close(standing.worst, 32.0);
+        assert_close(standing.best, 92.0);
+        assert_close(Some(standing.remaining_weight), 60.0);
+        assert_close(standing.needed(80.0), 80.0);
+        assert_close(standing.needed(50.0), 30.0);
+    }
+
+    #[test]
+    fn fully_graded() {
+        let standing = Standing::of(&[component(40.0, Some(80.0)), component(60.0, Some(90.0))]);
+
+        assert_close(standing.current, 86.0);
+        assert_close(standing.worst, 86.0);
+        assert_close(standing.best, 86.0);
+        assert_eq!(standing.needed(90.0), None);
+    }
+
+    #[test]
+    fn parses_percentages() {
+        assert_close(parse_score("85").ok(), 85.0);
+        assert_close(parse_score("85%").ok(), 85.0);
+        assert_close(parse_score(" 105 ").ok(), 105.0);
+    }
+
+    #[test]
+    fn parses_fractions() {
+        assert_close(parse_score("17/20").ok(), 85.0);
+        assert_close(parse_score("0 / 4").ok(), 0.0);
+    }
+
+    #[test]
+    fn rejects_zero_denominators() {
+        assert!(parse_score("17/0").is_err());
+    }
+
+    #[test]
+    fn rejects_negative_and_non_finite_scores() {
+        for score in [
+            "-5", "-3/4", "3/-4", "nan", "inf", "-inf", "1/inf", "abc", "1/2/3",
+        ] {
+            assert!(parse_score(score).is_err(), "{score} should be rejected");
+        }
+    }
+
+    #[test]
+    fn parses_weights() {
+        assert_close(parse_weight("40").ok(), 40.0);
+        assert_close(parse_weight(" 12.5 ").ok(), 12.5);
+        assert_close(parse_weight("0").ok(), 0.0);
+    }
+
+    #[test]
+    fn rejects_negative_and_non_finite_weights() {
+        for weight in ["-5", "nan", "inf", "-inf", "abc", ""] {
+            assert!(parse_weight(weight).is_err(), "{weight} should be rejected");
+        }
+    }
+}
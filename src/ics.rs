@@ -0,0 +1,365 @@
+//! Just enough of iCalendar (RFC 5545) to export and import unii's calendars.
+
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, Utc};
+
+use crate::Error;
+
+const DATETIME_FORMAT: &str = "%Y%m%dT%H%M%S";
+
+/// Writes an iCalendar stream, escaping text and folding long lines.
+pub struct Writer {
+    out: String,
+}
+
+impl Writer {
+    pub fn new() -> Self {
+        let mut writer = Self { out: String::new() };
+
+        writer.property("BEGIN", "VCALENDAR");
+        writer.property("VERSION", "2.0");
+        writer.property("PRODID", "-//unii//unii//EN");
+        writer.property("CALSCALE", "GREGORIAN");
+
+        writer
+    }
+
+    /// Write a property whose value is already in iCalendar syntax.
+    pub fn property(&mut self, name: &str, value: &str) {
+        let line = format!("{name}:{value}");
+
+        self.out.push_str(&fold(&line));
+        self.out.push_str("\r\n");
+    }
+
+    /// Write a `TEXT` property, escaping its value.
+    pub fn text(&mut self, name: &str, value: &str) {
+        self.property(name, &escape(value));
+    }
+
+    /// Write a floating (local time) date-time property.
+    pub fn datetime(&mut self, name: &str, datetime: NaiveDateTime) {
+        self.property(name, &format_datetime(datetime));
+    }
+
+    pub fn finish(mut self) -> String {
+        self.property("END", "VCALENDAR");
+
+        self.out
+    }
+}
+
+pub fn format_datetime(datetime: NaiveDateTime) -> String {
+    datetime.format(DATETIME_FORMAT).to_string()
+}
+
+pub fn format_date(date: NaiveDate) -> String {
+    date.format("%Y%m%d").to_string()
+}
+
+/// Escape a `TEXT` value.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Fold a content line so that no line is longer than 75 octets.
+fn fold(line: &str) -> String {
+    const LIMIT: usize = 75;
+
+    let mut folded = String::with_capacity(line.len());
+    let mut length = 0;
+
+    for c in line.chars() {
+        if length + c.len_utf8() > LIMIT {
+            folded.push_str("\r\n ");
+            length = 1;
+        }
+
+        folded.push(c);
+        length += c.len_utf8();
+    }
+
+    folded
+}
+
+/// A timed event read from an iCalendar file.
+pub struct Event {
+    pub summary: String,
+    pub location: Option<String>,
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+
+    /// The last day of the event's recurrence rule, if it has one.
+    pub until: Option<NaiveDate>,
+}
+
+/// Read every timed `VEVENT` in `input`. All-day events are skipped.
+///
+/// Components nested in an event, like its `VALARM`s, are skipped so that their properties are not
+/// mistaken for the event's.
+pub fn parse(input: &str) -> Result<Vec<Event>, Error> {
+    let mut events = Vec::new();
+
+    let mut current: Option<Vec<(String, String)>> = None;
+    let mut nested: Vec<String> = Vec::new();
+
+    for line in unfold(input) {
+        let (name, value) = split_line(&line)?;
+
+        match (name.as_str(), value.as_str()) {
+            ("BEGIN", "VEVENT") if current.is_none() => current = Some(Vec::new()),
+            ("BEGIN", _) if current.is_some() => nested.push(value),
+            ("END", _) if nested.last() == Some(&value) => {
+                nested.pop();
+            }
+            ("END", _) if !nested.is_empty() => {
+                Err(Error::IcsInvalid(format!("unexpected END:{value}")))?;
+            }
+            ("END", "VEVENT") => {
+                let properties = current
+                    .take()
+                    .ok_or_else(|| Error::IcsInvalid("unexpected END:VEVENT".to_owned()))?;
+
+                if let Some(event) = event(&properties)? {
+                    events.push(event);
+                }
+            }
+            _ => {
+                if let Some(properties) = current.as_mut().filter(|_| nested.is_empty()) {
+                    properties.push((name, value));
+                }
+            }
+        }
+    }
+
+    Ok(events)
+}
+
+fn event(properties: &[(String, String)]) -> Result<Option<Event>, Error> {
+    let get = |name: &str| {
+        properties
+            .iter()
+            .find(|(property, _)| property == name)
+            .map(|(_, value)| value.as_str())
+    };
+
+    let (Some(start), Some(end)) = (get("DTSTART"), get("DTEND")) else {
+        return Ok(None);
+    };
+
+    let (Some(start), Some(end)) = (parse_datetime(start), parse_datetime(end)) else {
+        return Ok(None);
+    };
+
+    let until = get("RRULE")
+        .and_then(|rule| {
+            rule.split(';')
+                .find_map(|part| part.strip_prefix("UNTIL="))
+                .map(ToOwned::to_owned)
+        })
+        .map(|until| {
+            parse_datetime(&until)
+                .map(|until| until.date())
+                .or_else(|| NaiveDate::parse_from_str(&until, "%Y%m%d").ok())
+                .ok_or_else(|| Error::IcsInvalid(format!("invalid UNTIL: {until}")))
+        })
+        .transpose()?;
+
+    Ok(Some(Event {
+        summary: get("SUMMARY").map(unescape).unwrap_or_default(),
+        location: get("LOCATION")
+            .map(unescape)
+            .filter(|location| !location.is_empty()),
+        start,
+        end,
+        until,
+    }))
+}
+
+/// Parse a date-time, converting UTC times (ending in `Z`) to local time.
+fn parse_datetime(value: &str) -> Option<NaiveDateTime> {
+    if let Some(utc) = value.strip_suffix('Z') {
+        let utc = NaiveDateTime::parse_from_str(utc, DATETIME_FORMAT).ok()?;
+
+        return Some(
+            DateTime::<Utc>::from_utc(utc, Utc)
+                .with_timezone(&Local)
+                .naive_local(),
+        );
+    }
+
+    NaiveDateTime::parse_from_str(value, DATETIME_FORMAT).ok()
+}
+
+/// Join folded content lines back together.
+fn unfold(input: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for line in input.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ if line.is_empty() => {}
+            _ => lines.push(line.to_owned()),
+        }
+    }
+
+    lines
+}
+
+/// Split a content line into its name (without parameters) and value.
+fn split_line(line: &str) -> Result<(String, String), Error> {
+    let mut quoted = false;
+
+    let colon = line
+        .char_indices()
+        .find(|&(_, c)| {
+            if c == '"' {
+                quoted = !quoted;
+            }
+
+            c == ':' && !quoted
+        })
+        .map(|(index, _)| index)
+        .ok_or_else(|| Error::IcsInvalid(format!("missing ':' in line: {line}")))?;
+
+    let (name, value) = (&line[..colon], &line[colon + 1..]);
+    let name = name.split(';').next().unwrap_or(name).to_uppercase();
+
+    Ok((name, value.to_owned()))
+}
+
+/// Undo the escaping of a `TEXT` value.
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n' | 'N') => unescaped.push('\n'),
+                Some(c) => unescaped.push(c),
+                None => {}
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::{escape, fold, parse, unescape, unfold, Writer};
+
+    #[test]
+    fn escape_round_trips() {
+        let text = "Room 1, Floor 2; back\\slash\nnewline";
+
+        assert_eq!(escape(text), "Room 1\\, Floor 2\\; back\\\\slash\\nnewline");
+        assert_eq!(unescape(&escape(text)), text);
+    }
+
+    #[test]
+    fn fold_round_trips() {
+        let line = format!("SUMMARY:{}", "é".repeat(100));
+        let folded = fold(&line);
+
+        assert!(folded.split("\r\n").all(|line| line.len() <= 75));
+        assert_eq!(unfold(&folded), vec![line]);
+    }
+
+    #[test]
+    fn short_lines_are_not_folded() {
+        assert_eq!(fold("SUMMARY:short"), "SUMMARY:short");
+    }
+
+    #[test]
+    fn unfold_joins_tab_continuations() {
+        assert_eq!(
+            unfold("SUMMARY:Intro\r\n\tduction\r\nEND:VEVENT\r\n"),
+            vec!["SUMMARY:Introduction", "END:VEVENT"]
+        );
+    }
+
+    #[test]
+    fn parses_written_events() {
+        let summary = format!("CS101 Intro, part 1; {}", "long ".repeat(20));
+        let start = NaiveDate::from_ymd_opt(2023, 9, 6)
+            .and_then(|date| date.and_hms_opt(9, 30, 0))
+            .expect("date should be valid");
+        let end = start + chrono::Duration::minutes(80);
+
+        let mut calendar = Writer::new();
+        calendar.property("BEGIN", "VEVENT");
+        calendar.datetime("DTSTART", start);
+        calendar.datetime("DTEND", end);
+        calendar.property("RRULE", "FREQ=WEEKLY;UNTIL=20231208T235959");
+        calendar.text("SUMMARY", &summary);
+        calendar.text("LOCATION", "MC 2065");
+        calendar.property("END", "VEVENT");
+
+        let events = parse(&calendar.finish()).expect("calendar should parse");
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].summary, summary);
+        assert_eq!(events[0].location.as_deref(), Some("MC 2065"));
+        assert_eq!(events[0].start, start);
+        assert_eq!(events[0].end, end);
+        assert_eq!(events[0].until, NaiveDate::from_ymd_opt(2023, 12, 8));
+    }
+
+    #[test]
+    fn ignores_properties_of_nested_components() {
+        let input = "BEGIN:VCALENDAR\r\n\
+                     BEGIN:VEVENT\r\n\
+                     SUMMARY:Lecture\r\n\
+                     DTSTART:20230906T093000\r\n\
+                     DTEND:20230906T105000\r\n\
+                     BEGIN:VALARM\r\n\
+                     SUMMARY:Reminder\r\n\
+                     LOCATION:Elsewhere\r\n\
+                     TRIGGER:-PT1H\r\n\
+                     END:VALARM\r\n\
+                     END:VEVENT\r\n\
+                     END:VCALENDAR\r\n";
+
+        let events = parse(input).expect("calendar should parse");
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].summary, "Lecture");
+        assert_eq!(events[0].location, None);
+    }
+
+    #[test]
+    fn rejects_mismatched_nested_components() {
+        let input = "BEGIN:VEVENT\r\nBEGIN:VALARM\r\nEND:VTODO\r\nEND:VEVENT\r\n";
+
+        assert!(parse(input).is_err());
+    }
+
+    #[test]
+    fn skips_all_day_events() {
+        let input = "BEGIN:VEVENT\r\n\
+                     SUMMARY:Holiday\r\n\
+                     DTSTART;VALUE=DATE:20231009\r\n\
+                     DTEND;VALUE=DATE:20231010\r\n\
+                     END:VEVENT\r\n";
+
+        assert!(parse(input).expect("calendar should parse").is_empty());
+    }
+}
@@ -0,0 +1,331 @@
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+use chrono::{Datelike, NaiveDate};
+use clap::Parser;
+use regex::Regex;
+
+use crate::{
+    ics, prompt,
+    schedule::{Meeting, MeetingKind},
+    settings::DEFAULT_IMPORT_PATTERN,
+    Course, Error, Settings, Term,
+};
+
+#[derive(Parser)]
+pub struct Args {
+    /// The iCalendar file to import
+    file: PathBuf,
+
+    /// The term to put the courses in (defaults to the current term)
+    #[clap(short, long)]
+    term: Option<String>,
+
+    /// A regex matching the course code in each event's summary
+    ///
+    /// The first capture group is used if there is one, otherwise the whole match.
+    /// Defaults to the `import-pattern` setting.
+    #[clap(short, long)]
+    pattern: Option<String>,
+
+    /// Only show what would be imported
+    #[clap(short = 'n', long)]
+    dry_run: bool,
+
+    /// Do not ask for confirmation
+    #[clap(short, long)]
+    yes: bool,
+}
+
+/// The meetings and date range found in a timetable.
+#[derive(Default)]
+struct Timetable {
+    courses: BTreeMap<String, Vec<Meeting>>,
+    dates: Option<(NaiveDate, NaiveDate)>,
+    skipped: usize,
+    /// Events that are not a weekly meeting: zero-length or crossing midnight.
+    untimed: usize,
+}
+
+/// A course to create or update.
+struct Import {
+    code: String,
+    existing: Option<Course>,
+    meetings: Vec<Meeting>,
+}
+
+pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
+    let pattern = Regex::new(
+        args.pattern
+            .as_deref()
+            .or(settings.import_pattern.as_deref())
+            .unwrap_or(DEFAULT_IMPORT_PATTERN),
+    )?;
+
+    let timetable = read_timetable(&fs::read_to_string(&args.file)?, &pattern)?;
+
+    let term_name = match args.term {
+        Some(term) => term,
+        None => Term::current(settings)?
+            .map(|term| term.name().to_owned())
+            .ok_or(Error::ImportTermMissing)?,
+    };
+    let term_exists = Term::open(settings, &term_name)?.is_some();
+
+    match (term_exists, timetable.dates) {
+        (true, _) => println!("Term {term_name} (existing)"),
+        (false, Some((start, end))) => {
+            println!("Term {term_name} (new, {start} to {end})");
+        }
+        (false, None) => println!("Term {term_name} (new)"),
+    }
+
+    let mut imports = Vec::new();
+    for (code, meetings) in timetable.courses {
+        let import = plan(settings, code, meetings)?;
+        preview(&import);
+        imports.push(import);
+    }
+
+    if timetable.skipped > 0 {
+        println!(
+            "Skipped {} event(s) without a course code",
+            timetable.skipped
+        );
+    }
+
+    if timetable.untimed > 0 {
+        println!(
+            "Skipped {} event(s) that are zero-length or cross midnight",
+            timetable.untimed
+        );
+    }
+
+    if args.dry_run {
+        return Ok(());
+    }
+
+    if !args.yes && !prompt::confirm("Import?")? {
+        println!("Aborted");
+
+        return Ok(());
+    }
+
+    if !term_exists {
+        if let Some((start, end)) = timetable.dates {
+            Term::new(settings, term_name.clone(), start, end, Vec::new())?;
+        }
+    }
+
+    for import in imports {
+        let mut course = import.existing.unwrap_or_else(|| Course::new(import.code));
+
+        course.meetings.extend(import.meetings);
+        if course.term.is_none() {
+            course.term = Some(term_name.clone());
+        }
+
+        if course.dir(settings).exists() {
+            course.write(settings)?;
+
+            println!("Updated course: {}", course.code());
+        } else {
+            course.create(settings)?;
+
+            println!("Created course: {}", course.code());
+        }
+    }
+
+    Ok(())
+}
+
+fn read_timetable(input: &str, pattern: &Regex) -> color_eyre::Result<Timetable> {
+    let mut timetable = Timetable::default();
+
+    for event in ics::parse(input)? {
+        if event.end <= event.start || event.end.date() != event.start.date() {
+            timetable.untimed += 1;
+            continue;
+        }
+
+        let Some(captures) = pattern.captures(&event.summary) else {
+            timetable.skipped += 1;
+            continue;
+        };
+
+        let code = captures
+            .get(1)
+            .or_else(|| captures.get(0))
+            .map(|code| code.as_str().split_whitespace().collect::<String>())
+            .unwrap_or_default();
+
+        let meeting = Meeting {
+            kind: guess_kind(&event.summary),
+            day: event.start.weekday(),
+            start: event.start.time(),
+            end: event.end.time(),
+            location: event.location,
+            room: None,
+        };
+
+        let meetings = timetable.courses.entry(code).or_default();
+        if !meetings.contains(&meeting) {
+            meetings.push(meeting);
+        }
+
+        let first = event.start.date();
+        let last = event.until.unwrap_or(first).max(first);
+        timetable.dates = Some(timetable.dates.map_or((first, last), |(start, end)| {
+            (start.min(first), end.max(last))
+        }));
+    }
+
+    Ok(timetable)
+}
+
+fn plan(
+    settings: &Settings,
+    code: String,
+    mut meetings: Vec<Meeting>,
+) -> color_eyre::Result<Import> {
+    let existing = Course::open(settings, &code)?;
+
+    if let Some(course) = &existing {
+        meetings.retain(|meeting| !course.meetings.contains(meeting));
+    }
+
+    meetings.sort_by_key(|meeting| (meeting.day.num_days_from_monday(), meeting.start));
+
+    Ok(Import {
+        code,
+        existing,
+        meetings,
+    })
+}
+
+fn preview(import: &Import) {
+    println!(
+        "{} ({})",
+        import.code,
+        if import.existing.is_some() {
+            "existing course"
+        } else {
+            "new course"
+        }
+    );
+
+    if import.meetings.is_empty() {
+        println!("  no new meetings");
+    }
+
+    for meeting in &import.meetings {
+        let line = format!(
+            "  + {} {} {}-{} {}",
+            meeting.kind,
+            meeting.day,
+            meeting.start.format("%H:%M"),
+            meeting.end.format("%H:%M"),
+            meeting.place()
+        );
+
+        println!("{}", line.trim_end());
+    }
+}
+
+fn guess_kind(summary: &str) -> MeetingKind {
+    let summary = summary.to_lowercase();
+
+    if summary.contains("office") {
+        MeetingKind::OfficeHours
+    } else if summary.contains("tut") {
+        MeetingKind::Tutorial
+    } else if summary.contains("lab") {
+        MeetingKind::Lab
+    } else {
+        MeetingKind::Lecture
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveTime, Weekday};
+    use regex::Regex;
+
+    use super::{guess_kind, read_timetable};
+    use crate::{schedule::MeetingKind, settings::DEFAULT_IMPORT_PATTERN};
+
+    fn event(summary: &str, start: &str, end: &str) -> String {
+        format!(
+            "BEGIN:VEVENT\r\n\
+             SUMMARY:{summary}\r\n\
+             DTSTART:{start}\r\n\
+             DTEND:{end}\r\n\
+             END:VEVENT\r\n"
+        )
+    }
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).expect("time should be valid")
+    }
+
+    #[test]
+    fn reads_meetings_by_course() {
+        let input = [
+            event("CS 101 Lecture", "20230906T093000", "20230906T105000"),
+            event("CS101 Lecture", "20230913T093000", "20230913T105000"),
+            event("MATH135 Tutorial", "20230908T143000", "20230908T152000"),
+            event("Club meeting", "20230907T180000", "20230907T190000"),
+        ]
+        .concat();
+        let pattern = Regex::new(DEFAULT_IMPORT_PATTERN).expect("pattern should be valid");
+
+        let timetable = read_timetable(&input, &pattern).expect("timetable should parse");
+
+        assert_eq!(
+            timetable.courses.keys().collect::<Vec<_>>(),
+            ["CS101", "MATH135"]
+        );
+
+        let lectures = &timetable.courses["CS101"];
+        assert_eq!(lectures.len(), 1);
+        assert!(lectures[0].kind == MeetingKind::Lecture);
+        assert_eq!(lectures[0].day, Weekday::Wed);
+        assert_eq!(
+            (lectures[0].start, lectures[0].end),
+            (time(9, 30), time(10, 50))
+        );
+
+        assert!(timetable.courses["MATH135"][0].kind == MeetingKind::Tutorial);
+        assert_eq!(timetable.skipped, 1);
+        assert_eq!(
+            timetable.dates,
+            NaiveDate::from_ymd_opt(2023, 9, 6).zip(NaiveDate::from_ymd_opt(2023, 9, 13))
+        );
+    }
+
+    #[test]
+    fn skips_zero_length_and_overnight_events() {
+        let input = [
+            event("CS101 Assignment 1", "20230915T235900", "20230915T235900"),
+            event("CS101 Lecture", "20230906T105000", "20230906T093000"),
+            event("CS101 Lab", "20230906T220000", "20230907T010000"),
+        ]
+        .concat();
+        let pattern = Regex::new(DEFAULT_IMPORT_PATTERN).expect("pattern should be valid");
+
+        let timetable = read_timetable(&input, &pattern).expect("timetable should parse");
+
+        assert!(timetable.courses.is_empty());
+        assert_eq!(timetable.dates, None);
+        assert_eq!(timetable.untimed, 3);
+    }
+
+    #[test]
+    fn guesses_kinds_from_summaries() {
+        assert!(guess_kind("CS101 LEC 001") == MeetingKind::Lecture);
+        assert!(guess_kind("CS101 TUT 101") == MeetingKind::Tutorial);
+        assert!(guess_kind("CS101 Tutorial") == MeetingKind::Tutorial);
+        assert!(guess_kind("PHYS2 Lab") == MeetingKind::Lab);
+        assert!(guess_kind("CS101 Office Hours") == MeetingKind::OfficeHours);
+        assert!(guess_kind("CS101") == MeetingKind::Lecture);
+    }
+}
@@ -0,0 +1,23 @@
+mod ics;
+
+use clap::Parser;
+
+use crate::Settings;
+
+#[derive(Parser)]
+pub struct Args {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Parser)]
+enum Command {
+    /// Import courses and meetings from an iCalendar (.ics) timetable
+    Ics(ics::Args),
+}
+
+pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
+    match args.command {
+        Command::Ics(args) => ics::run(settings, args),
+    }
+}
@@ -9,17 +9,32 @@
 
 //! unii: A command-line university work management tool.
 
+mod config;
 mod course;
+mod due;
 mod error;
+mod export;
+mod format;
+mod gpa;
+mod grade;
+mod ics;
+mod import;
+mod migrate;
+mod prompt;
+mod schedule;
 mod settings;
 mod template;
+mod term;
+mod trash;
 
 use std::path::PathBuf;
 
 use clap::Parser;
 
 use self::course::Course;
+use self::format::Format;
 use self::settings::{Settings, DEFAULT_COURSES_DIR, DEFAULT_SETTINGS_FILE};
+use self::term::Term;
 
 pub use self::error::Error;
 
@@ -36,15 +51,53 @@ struct Args {
     /// The path to the directory where courses are stored
     #[clap(long, default_value = DEFAULT_COURSES_DIR.as_os_str())]
     courses_dir: PathBuf,
+
+    /// The format to print listings in
+    #[clap(long, global = true, value_enum, default_value_t)]
+    format: Format,
 }
 
 #[derive(Parser)]
 enum Command {
+    /// Read and change settings, like the student profile
+    Config(config::Args),
+
     /// Manage courses
     Course(course::Args),
 
+    /// Manage deadlines
+    Due(due::Args),
+
+    /// Export unii's data for use in other programs
+    Export(export::Args),
+
+    /// Calculate term and cumulative GPA
+    Gpa(gpa::Args),
+
+    /// Manage the grades of a course
+    #[clap(aliases = ["grades"])]
+    Grade(grade::Args),
+
+    /// Import data from other programs
+    Import(import::Args),
+
+    /// Move files stored in an older layout to where unii now expects them
+    Migrate(migrate::Args),
+
     /// Manage templates
     Template(template::Args),
+
+    /// Show today's classes
+    Today(schedule::TodayArgs),
+
+    /// Show this week's classes
+    Week(schedule::WeekArgs),
+
+    /// Manage terms
+    Term(term::Args),
+
+    /// Manage removed courses
+    Trash(trash::Args),
 }
 
 /// Main entrypoint to the cli.
@@ -55,13 +108,25 @@ enum Command {
 /// if the settings file cannot be opened or created, or if the command fails.
 pub fn run() -> color_eyre::Result<()> {
     let args = Args::parse();
-    let settings = Settings {
+    let mut settings = Settings {
         path: args.courses_dir,
+        ..Settings::default()
     }
     .open_or_create_at(&args.settings_file)?;
 
     match args.command {
-        Command::Course(args) => course::run(&settings, args),
-        Command::Template(args) => template::run(&settings, args),
+        Command::Config(config_args) => config::run(&settings, args.format, config_args),
+        Command::Course(course_args) => course::run(&settings, args.format, course_args),
+        Command::Due(due_args) => due::run(&settings, args.format, due_args),
+        Command::Export(export_args) => export::run(&settings, export_args),
+        Command::Gpa(gpa_args) => gpa::run(&settings, args.format, gpa_args),
+        Command::Grade(grade_args) => grade::run(&settings, args.format, grade_args),
+        Command::Import(import_args) => import::run(&settings, import_args),
+        Command::Migrate(migrate_args) => migrate::run(&settings, migrate_args),
+        Command::Template(template_args) => template::run(&settings, args.format, template_args),
+        Command::Today(today_args) => schedule::today(&settings, args.format, today_args),
+        Command::Week(week_args) => schedule::week(&settings, args.format, week_args),
+        Command::Term(term_args) => term::run(&mut settings, args.format, term_args),
+        Command::Trash(trash_args) => trash::run(&settings, args.format, trash_args),
     }
 }
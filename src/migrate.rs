@@ -0,0 +1,67 @@
+use std::fs;
+
+use clap::Parser;
+
+use crate::Settings;
+
+#[derive(Parser)]
+pub struct Args {
+    /// Only show what would be moved
+    #[clap(short = 'n', long)]
+    dry_run: bool,
+}
+
+/// Move files stored in an older layout of the courses directory to where unii now expects them.
+pub fn run(settings: &Settings, Args { dry_run }: Args) -> color_eyre::Result<()> {
+    let legacy_dir = settings.legacy_template_dir();
+
+    // A course may be called `templates` too
+    if !legacy_dir.is_dir() || settings.course_yaml_path("templates").exists() {
+        println!("Nothing to migrate");
+
+        return Ok(());
+    }
+
+    let template_dir = settings.template_dir();
+    let mut moved = 0;
+
+    for entry in fs::read_dir(&legacy_dir)? {
+        let path = entry?.path();
+
+        if !path.is_file() || path.extension().map_or(true, |ext| ext != "yml") {
+            continue;
+        }
+
+        let new_path = template_dir.join(path.file_name().expect("file should have a name"));
+
+        if new_path.exists() {
+            println!(
+                "Skipped: {} (already exists at {})",
+                path.display(),
+                new_path.display()
+            );
+
+            continue;
+        }
+
+        if !dry_run {
+            fs::create_dir_all(&template_dir)?;
+            fs::rename(&path, &new_path)?;
+        }
+
+        println!("Moved: {} -> {}", path.display(), new_path.display());
+        moved += 1;
+    }
+
+    if !dry_run && fs::read_dir(&legacy_dir)?.next().is_none() {
+        fs::remove_dir(&legacy_dir)?;
+    }
+
+    if dry_run {
+        println!("Would migrate {moved} template(s)");
+    } else {
+        println!("Migrated {moved} template(s)");
+    }
+
+    Ok(())
+}
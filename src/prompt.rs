@@ -0,0 +1,25 @@
+use std::io::{self, BufRead, Write};
+
+/// Ask a yes/no question on stdin, defaulting to no.
+pub fn confirm(question: &str) -> io::Result<bool> {
+    print!("{question} [y/N] ");
+    io::stdout().flush()?;
+
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Ask for a line of input on stdin, without the surrounding whitespace.
+pub fn ask(question: &str) -> io::Result<String> {
+    print!("{question}");
+    io::stdout().flush()?;
+
+    let mut answer = String::new();
+    if io::stdin().lock().read_line(&mut answer)? == 0 {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+
+    Ok(answer.trim().to_owned())
+}
@@ -0,0 +1,85 @@
+mod today;
+mod types;
+mod week;
+
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use serde::Serialize;
+
+pub use self::today::{run as today, Args as TodayArgs};
+pub use self::types::{parse_time, Meeting, MeetingKind};
+pub use self::week::{run as week, Args as WeekArgs};
+
+use crate::{format::Table, Course, Settings, Term};
+
+/// A meeting on a specific day.
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Event {
+    date: NaiveDate,
+    course: String,
+    #[serde(flatten)]
+    meeting: Meeting,
+}
+
+/// Every meeting of an active course on `date`, in order.
+fn events_on(settings: &Settings, date: NaiveDate) -> color_eyre::Result<Vec<Event>> {
+    let mut events = Vec::new();
+
+    for course in Course::active(settings)? {
+        if let Some(term) = course_term(settings, &course)? {
+            if !term.contains(date) || term.is_break(date) {
+                continue;
+            }
+        }
+
+        for meeting in &course.meetings {
+            if meeting.day == date.weekday() {
+                events.push(Event {
+                    date,
+                    course: course.code().to_owned(),
+                    meeting: meeting.clone(),
+                });
+            }
+        }
+    }
+
+    events.sort_by_key(|event| (event.meeting.start, event.meeting.end));
+
+    Ok(events)
+}
+
+fn course_term(settings: &Settings, course: &Course) -> color_eyre::Result<Option<Term>> {
+    course
+        .term
+        .as_ref()
+        .map_or(Ok(None), |term| Term::open(settings, term))
+}
+
+fn time_range(start: NaiveTime, end: NaiveTime) -> String {
+    format!("{}-{}", start.format("%H:%M"), end.format("%H:%M"))
+}
+
+fn table(events: &[Event], with_day: bool) -> Table {
+    let mut headers = vec!["time", "course", "kind", "place"];
+    if with_day {
+        headers.insert(0, "day");
+    }
+
+    let mut table = Table::new(headers);
+
+    for event in events {
+        let mut row = vec![
+            time_range(event.meeting.start, event.meeting.end),
+            event.course.clone(),
+            event.meeting.kind.to_string(),
+            event.meeting.place(),
+        ];
+        if with_day {
+            row.insert(0, event.date.format("%a %b %-d").to_string());
+        }
+
+        table.push(row);
+    }
+
+    table
+}
@@ -0,0 +1,25 @@
+use chrono::{Local, NaiveDate};
+use clap::Parser;
+
+use crate::{Format, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    /// Show the agenda for this date instead (YYYY-MM-DD)
+    #[clap(short, long)]
+    date: Option<NaiveDate>,
+}
+
+pub fn run(settings: &Settings, format: Format, Args { date }: Args) -> color_eyre::Result<()> {
+    let date = date.unwrap_or_else(|| Local::now().date_naive());
+
+    let events = super::events_on(settings, date)?;
+
+    format.print(&events, |events| {
+        if events.is_empty() {
+            format!("No classes on {}\n", date.format("%A, %B %-d"))
+        } else {
+            super::table(events, false).to_string()
+        }
+    })
+}
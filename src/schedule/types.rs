@@ -0,0 +1,79 @@
+use std::fmt::{self, Display};
+
+use chrono::{NaiveTime, Weekday};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// A weekly class meeting of a course.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Meeting {
+    pub kind: MeetingKind,
+    pub day: Weekday,
+    #[serde(with = "hh_mm")]
+    pub start: NaiveTime,
+    #[serde(with = "hh_mm")]
+    pub end: NaiveTime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub room: Option<String>,
+}
+
+impl Meeting {
+    /// Whether the two meetings happen at the same time.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.day == other.day && self.start.max(other.start) < self.end.min(other.end)
+    }
+
+    /// The location and room, joined for display.
+    pub fn place(&self) -> String {
+        [self.location.as_deref(), self.room.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+#[derive(Serialize, Deserialize, ValueEnum, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum MeetingKind {
+    Lecture,
+    Tutorial,
+    Lab,
+    OfficeHours,
+}
+
+impl Display for MeetingKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Lecture => "lecture",
+            Self::Tutorial => "tutorial",
+            Self::Lab => "lab",
+            Self::OfficeHours => "office hours",
+        })
+    }
+}
+
+/// Parse a time written as `HH:MM` or `HH:MM:SS`.
+pub fn parse_time(s: &str) -> Result<NaiveTime, chrono::ParseError> {
+    NaiveTime::parse_from_str(s, "%H:%M").or_else(|_| NaiveTime::parse_from_str(s, "%H:%M:%S"))
+}
+
+/// (De)serialize times as `HH:MM`.
+mod hh_mm {
+    use chrono::NaiveTime;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub fn serialize<S: Serializer>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&time.format("%H:%M"))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NaiveTime, D::Error> {
+        let s = String::deserialize(deserializer)?;
+
+        super::parse_time(&s).map_err(D::Error::custom)
+    }
+}
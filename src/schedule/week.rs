@@ -0,0 +1,28 @@
+use chrono::{Duration, Local, NaiveDate};
+use clap::Parser;
+
+use crate::{term::week_start, Format, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    /// Show the week containing this date instead (YYYY-MM-DD)
+    #[clap(short, long)]
+    date: Option<NaiveDate>,
+}
+
+pub fn run(settings: &Settings, format: Format, Args { date }: Args) -> color_eyre::Result<()> {
+    let monday = week_start(date.unwrap_or_else(|| Local::now().date_naive()));
+
+    let mut events = Vec::new();
+    for day in 0..7 {
+        events.extend(super::events_on(settings, monday + Duration::days(day))?);
+    }
+
+    format.print(&events, |events| {
+        if events.is_empty() {
+            format!("No classes in the week of {}\n", monday.format("%B %-d"))
+        } else {
+            super::table(events, true).to_string()
+        }
+    })
+}
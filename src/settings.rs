@@ -1,13 +1,16 @@
 use std::{
+    collections::BTreeMap,
     fs,
     path::{Path, PathBuf},
 };
 
-use config::Config;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 
-use crate::course::{Course, COURSE_YAML};
+use crate::{
+    course::COURSE_YAML,
+    gpa::{default_grading_scales, GradingScale},
+};
 
 static CONFIG_DIR: Lazy<PathBuf> = Lazy::new(|| {
     dirs::config_dir()
@@ -23,21 +26,61 @@ pub static DEFAULT_COURSES_DIR: Lazy<PathBuf> = Lazy::new(|| {
         .join("unii")
 });
 
+/// The regex used to find course codes when importing a timetable, e.g. `CS 101` or `MATH135`.
+pub const DEFAULT_IMPORT_PATTERN: &str = r"\b([A-Z]{2,5}\s?\d{2,4}[A-Z]?)\b";
+
 #[derive(Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct Settings {
+    #[serde(skip)]
+    pub file: PathBuf,
+
     pub path: PathBuf,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opener: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_term: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub import_pattern: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grading_scale: Option<String>,
+
+    /// Grading scales in addition to (or replacing) the built-in ones.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub grading_scales: BTreeMap<String, GradingScale>,
+
+    /// Details about the student, available to templates as `profile`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profile: BTreeMap<String, serde_yaml::Value>,
+
+    /// Values for template context parameters, keyed by template name or `*` for all templates.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub defaults: BTreeMap<String, BTreeMap<String, serde_yaml::Value>>,
+
+    /// Directories of templates shared between courses, searched before the global templates.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shared_template_dirs: Vec<PathBuf>,
 }
 
 impl Settings {
     pub fn open_or_create_at<P: AsRef<Path>>(self, path: P) -> color_eyre::Result<Self> {
         let path = path.as_ref();
         if let Some(settings) = Self::try_open_from(path)? {
-            Ok(settings)
+            Ok(Self {
+                file: path.to_owned(),
+                ..settings
+            })
         } else {
             self.create_at(path)?;
             println!("Created settings file at: {}", path.display());
-            Ok(self)
+            Ok(Self {
+                file: path.to_owned(),
+                ..self
+            })
         }
     }
 
@@ -48,10 +91,9 @@ impl Settings {
             return Ok(None);
         }
 
-        let settings = Config::builder()
-            .add_source(config::File::from(path))
-            .build()?
-            .try_deserialize()?;
+        // Read with the same YAML library that writes the file, so that strings like `012345` come
+        // back as they were saved rather than as numbers
+        let settings = serde_yaml::from_str(&fs::read_to_string(path)?)?;
 
         Ok(Some(settings))
     }
@@ -62,6 +104,11 @@ impl Settings {
         fs::write(path, serde_yaml::to_string(&self)?).map_err(Into::into)
     }
 
+    /// Write the settings back to the file they were opened from.
+    pub fn save(&self) -> color_eyre::Result<()> {
+        self.create_at(&self.file)
+    }
+
     pub fn course_dir(&self, code: &str) -> PathBuf {
         self.path.join(code)
     }
@@ -78,24 +125,83 @@ impl Settings {
         self.course_unii_dir(code).join(&*COURSE_YAML)
     }
 
+    pub fn course_deadlines_path(&self, code: &str) -> PathBuf {
+        self.course_unii_dir(code).join("deadlines.yml")
+    }
+
+    pub fn unii_dir(&self) -> PathBuf {
+        self.path.join(".unii")
+    }
+
     pub fn template_dir(&self) -> PathBuf {
-        self.path.join(".unii").join("templates")
+        self.unii_dir().join("templates")
+    }
+
+    /// The shared template directories, with `~` expanded and relative paths resolved against
+    /// the courses directory.
+    pub fn shared_template_dirs(&self) -> Vec<PathBuf> {
+        self.shared_template_dirs
+            .iter()
+            .map(|dir| match (dir.strip_prefix("~"), dirs::home_dir()) {
+                (Ok(rest), Some(home)) => home.join(rest),
+                _ => self.path.join(dir),
+            })
+            .collect()
+    }
+
+    /// Where templates were stored before they moved into `.unii/templates`.
+    pub fn legacy_template_dir(&self) -> PathBuf {
+        self.path.join("templates")
+    }
+
+    pub fn term_dir(&self) -> PathBuf {
+        self.unii_dir().join("terms")
+    }
+
+    pub fn term_path(&self, name: &str) -> PathBuf {
+        self.term_dir().join(format!("{name}.yml"))
+    }
+
+    pub fn trash_dir(&self) -> PathBuf {
+        self.unii_dir().join("trash")
+    }
+
+    pub fn archive_dir(&self) -> PathBuf {
+        self.unii_dir().join("archive")
+    }
+
+    pub fn archive_term_dir(&self, term: &str) -> PathBuf {
+        self.archive_dir().join(term)
+    }
+
+    pub fn archived_course_dir(&self, term: &str, code: &str) -> PathBuf {
+        self.archive_term_dir(term).join(code)
     }
 
-    pub fn template_path(&self, source: Option<&Course>, name: &str) -> PathBuf {
-        source
-            .map_or_else(|| self.template_dir(), |source| source.template_dir(self))
-            .join(name)
-            .with_extension("yml")
+    /// Look up a grading scale, preferring configured scales over the built-in ones.
+    pub fn grading_scale(&self, name: &str) -> Option<GradingScale> {
+        self.grading_scales
+            .get(name)
+            .cloned()
+            .or_else(|| default_grading_scales().remove(name))
     }
 }
 
 impl Default for Settings {
     fn default() -> Self {
         Self {
+            file: DEFAULT_SETTINGS_FILE.clone(),
             path: dirs::home_dir()
                 .expect("could not find home directory")
                 .join("unii"),
+            opener: None,
+            current_term: None,
+            import_pattern: None,
+            grading_scale: None,
+            grading_scales: BTreeMap::new(),
+            profile: BTreeMap::new(),
+            defaults: BTreeMap::new(),
+            shared_template_dirs: Vec::new(),
         }
     }
 }
@@ -1,15 +1,77 @@
 use clap::Parser;
+use serde::Serialize;
 
-use super::Template;
-use crate::Settings;
+use super::{Scope, Template};
+use crate::{format::Table, Course, Error, Format, Settings};
 
 #[derive(Parser)]
-pub struct Args {}
+pub struct Args {
+    /// Only list the templates of this course
+    #[clap(short, long, aliases = ["course", "code"])]
+    course_code: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Listing {
+    scope: String,
+    name: String,
+    #[serde(flatten)]
+    template: Template,
+}
+
+pub fn run(settings: &Settings, format: Format, args: Args) -> color_eyre::Result<()> {
+    let scopes = if let Some(code) = args.course_code {
+        let course = Course::open(settings, &code)?.ok_or(Error::CourseDoesNotExist(code))?;
+
+        vec![Scope::Course(course.code().to_owned())]
+    } else {
+        let mut scopes = Scope::search_order(settings, None);
+
+        for course in Course::all(settings)? {
+            scopes.push(Scope::Course(course?.code().to_owned()));
+        }
 
-pub fn run(settings: &Settings, _: Args) -> color_eyre::Result<()> {
-    for template in Template::all(settings)? {
-        println!("{}", template?.name());
+        scopes
+    };
+
+    let mut listings = Vec::new();
+    for scope in scopes {
+        let mut templates =
+            Template::all_in(settings, scope)?.collect::<color_eyre::Result<Vec<_>>>()?;
+        templates.sort_by(|a, b| a.name().cmp(b.name()));
+
+        listings.extend(templates.into_iter().map(|template| Listing {
+            scope: template.scope().to_string(),
+            name: template.name().to_owned(),
+            template,
+        }));
     }
 
-    Ok(())
+    format.print(&listings, |listings| {
+        let mut table = Table::new(vec!["scope", "name", "description", "parameters", "output"]);
+
+        for listing in listings {
+            let template = &listing.template;
+
+            table.push(vec![
+                listing.scope.clone(),
+                listing.name.clone(),
+                template.description().unwrap_or_default().to_owned(),
+                template
+                    .context_parameters()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                format!(
+                    "{}/{}",
+                    template.pluralized_name(),
+                    template.directory_name()
+                ),
+            ]);
+        }
+
+        table.to_string()
+    })
 }
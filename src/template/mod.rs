@@ -2,12 +2,13 @@ mod list;
 mod new;
 mod render;
 mod types;
+mod which;
 
 use clap::Parser;
 
-pub use self::types::Template;
+pub use self::types::{Scope, Template};
 
-use crate::Settings;
+use crate::{Format, Settings};
 
 #[derive(Parser)]
 pub struct Args {
@@ -17,7 +18,7 @@ pub struct Args {
 
 #[derive(Parser)]
 enum Command {
-    /// Create a new course
+    /// Create a new template
     #[clap(aliases = ["create", "add"])]
     New(new::Args),
 
@@ -28,12 +29,16 @@ enum Command {
     /// List all templates
     #[clap(aliases = ["ls"])]
     List(list::Args),
+
+    /// Show which template file is used for a name, and which ones it shadows
+    Which(which::Args),
 }
 
-pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
+pub fn run(settings: &Settings, format: Format, args: Args) -> color_eyre::Result<()> {
     match args.command {
         Command::New(args) => new::run(settings, args),
-        Command::Render(args) => render::run(settings, args),
-        Command::List(args) => list::run(settings, args),
+        Command::Render(args) => render::run(settings, format, args),
+        Command::List(args) => list::run(settings, format, args),
+        Command::Which(args) => which::run(settings, format, args),
     }
 }
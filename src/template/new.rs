@@ -1,7 +1,7 @@
 use clap::Parser;
 
-use super::Template;
-use crate::Settings;
+use super::{Scope, Template};
+use crate::{Course, Error, Settings};
 
 #[derive(Parser)]
 pub struct Args {
@@ -11,12 +11,39 @@ pub struct Args {
     /// The pluralized name of the template
     #[clap(long)]
     pluralized_name: Option<String>,
+
+    /// A short description of what the template is for
+    #[clap(short, long)]
+    description: Option<String>,
+
+    /// Create the template for only this course instead of globally
+    #[clap(short, long, aliases = ["course", "code"])]
+    course_code: Option<String>,
 }
 
 pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
-    let template = Template::from_name(settings, args.name, args.pluralized_name)?;
+    let scope = match args.course_code {
+        Some(code) => {
+            let course = Course::open(settings, &code)?.ok_or(Error::CourseDoesNotExist(code))?;
+
+            Scope::Course(course.code().to_owned())
+        }
+        None => Scope::Global,
+    };
+
+    let template = Template::from_name(
+        settings,
+        args.name,
+        args.pluralized_name,
+        args.description,
+        scope,
+    )?;
 
-    println!("Created template: {}", template.name());
+    println!(
+        "Created template: {} ({})",
+        template.path(settings).display(),
+        template.scope()
+    );
 
     Ok(())
 }
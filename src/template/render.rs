@@ -1,10 +1,19 @@
-use std::convert::Infallible;
+use std::{
+    convert::Infallible,
+    fmt::Write,
+    io::{self, IsTerminal},
+    path::PathBuf,
+};
 
 use clap::Parser;
-use color_eyre::Report;
+use serde::Serialize;
+use serde_json::Value;
 
-use super::Template;
-use crate::{Course, Error, Settings};
+use super::{
+    types::{Binding, Invocation, Kind, Parameter, Plan, Source},
+    Template,
+};
+use crate::{course::format_size, format::Table, prompt, Course, Error, Format, Settings};
 
 #[derive(Parser)]
 pub struct Args {
@@ -14,20 +23,32 @@ pub struct Args {
 
     /// The context to use when rendering the template
     #[clap(value_parser = parse_context, name = "KEY=VALUE")]
-    context: Vec<(String, serde_json::Value)>,
+    context: Vec<(String, String)>,
 
-    /// The course code to render the template under
+    /// Do not prompt for parameters which were not given
+    #[clap(long)]
+    no_input: bool,
+
+    /// Show where the value of each context parameter came from
+    ///
+    /// Written to stderr, so that it stays out of the `--dry-run` output.
+    #[clap(long)]
+    explain: bool,
+
+    /// Only show the directory, files and commands rendering would create and run
+    #[clap(short = 'n', long)]
+    dry_run: bool,
+
+    /// The course code to render the template under (defaults to the course before the template
+    /// name, then the course containing the working directory)
     #[clap(short, long, aliases = ["course", "code"])]
     course_code: Option<String>,
 }
 
-fn parse_context(s: &str) -> Result<(String, serde_json::Value), String> {
+fn parse_context(s: &str) -> Result<(String, String), String> {
     let (key, value) = s.split_once('=').ok_or("invalid context: missing '='")?;
 
-    let value = serde_json::from_str(value)
-        .unwrap_or_else(|_| serde_json::Value::String(value.to_string()));
-
-    Ok((key.to_string(), value))
+    Ok((key.to_string(), value.to_string()))
 }
 
 #[allow(clippy::unnecessary_wraps)]
@@ -38,42 +59,372 @@ fn parse_course_template(s: &str) -> Result<(Option<String>, String), Infallible
     ))
 }
 
-pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
+/// Find the template called `name` for rendering under `course`.
+///
+/// A `source_code` other than the course's own takes templates from that course instead. Either way
+/// the course's templates are tried first, then the shared and global ones.
+fn find_template(
+    settings: &Settings,
+    course: &Course,
+    source_code: Option<&str>,
+    name: &str,
+) -> color_eyre::Result<Option<Template>> {
+    let source = match source_code {
+        Some(code) if code != course.code() => Some(
+            Course::open(settings, code)?
+                .ok_or_else(|| Error::CourseDoesNotExist(code.to_owned()))?,
+        ),
+        _ => None,
+    };
+
+    Template::resolve(settings, Some(source.as_ref().unwrap_or(course)), name)
+}
+
+pub fn run(settings: &Settings, format: Format, args: Args) -> color_eyre::Result<()> {
     let (source_code, template_name) = args.name;
 
-    let (source_code, course_code) = match (source_code, args.course_code) {
-        (s, Some(c)) => (s, c),
-        (Some(s), None) => (Some(s.clone()), s),
-        (None, None) => Err(Error::TemplateCourseCodeMissing)?,
+    let course = match args.course_code.or_else(|| source_code.clone()) {
+        Some(code) => Course::open(settings, &code)?.ok_or(Error::CourseDoesNotExist(code))?,
+        None => Course::current(settings)?.ok_or(Error::TemplateCourseCodeMissing)?,
     };
 
-    let source = source_code
-        .as_ref()
-        .map(|source| {
-            Course::open(settings, source)?
-                .ok_or_else(|| Report::from(Error::CourseDoesNotExist(source.clone())))
+    let template = find_template(settings, &course, source_code.as_deref(), &template_name)?
+        .ok_or(Error::TemplateDoesNotExist(source_code, template_name))?;
+
+    let mut bindings = args
+        .context
+        .into_iter()
+        .map(|(name, raw)| Binding {
+            name,
+            value: Value::String(raw),
+            source: Source::Argument,
         })
-        .transpose()?;
+        .collect::<Vec<_>>();
 
-    let course =
-        Course::open(settings, &course_code)?.ok_or(Error::CourseDoesNotExist(course_code))?;
+    for preset in template.preset_bindings(settings, &course)? {
+        if !bindings.iter().any(|binding| binding.name == preset.name) {
+            bindings.push(preset);
+        }
+    }
 
-    let template = Template::open(settings, source.as_ref(), &template_name)?
-        .ok_or(Error::TemplateDoesNotExist(source_code, template_name))?;
+    if !args.no_input && io::stdin().is_terminal() {
+        for parameter in template.context_parameters() {
+            if bindings
+                .iter()
+                .any(|binding| binding.name == parameter.name)
+            {
+                continue;
+            }
 
-    let context = args
-        .context
+            if let Some(raw) = ask(parameter)? {
+                bindings.push(Binding {
+                    name: parameter.name.clone(),
+                    value: Value::String(raw),
+                    source: Source::Prompt,
+                });
+            }
+        }
+    }
+
+    let bindings = template.context(bindings)?;
+
+    if args.explain {
+        let mut table = Table::new(vec!["parameter", "value", "source"]);
+
+        for binding in &bindings {
+            table.push(vec![
+                binding.name.clone(),
+                match &binding.value {
+                    Value::String(s) => s.clone(),
+                    value => value.to_string(),
+                },
+                binding.source.to_string(),
+            ]);
+        }
+
+        eprint!("{table}");
+    }
+
+    let context = bindings
         .into_iter()
-        .map(|(key, value)| {
-            if template.context_parameters().contains(&key) {
-                Ok((key, value))
-            } else {
-                Err(Error::TemplateContextParameterDoesNotExist(key))
+        .map(|binding| (binding.name, binding.value))
+        .collect();
+
+    let plan = template.plan(settings, &course, &context)?;
+
+    if args.dry_run {
+        return format.print(&DryRun::from(&plan), DryRun::human);
+    }
+
+    plan.apply()
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct DryRun {
+    directory: PathBuf,
+    files: Vec<PlannedFile>,
+    pre_render: Vec<PlannedStep>,
+    post_render: Vec<PlannedStep>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct PlannedFile {
+    path: PathBuf,
+    size: u64,
+    content: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct PlannedStep {
+    command: String,
+    dir: PathBuf,
+    continue_on_error: bool,
+}
+
+impl From<&Plan> for DryRun {
+    fn from(plan: &Plan) -> Self {
+        let steps = |invocations: &[Invocation]| {
+            invocations
+                .iter()
+                .map(|invocation| PlannedStep {
+                    command: invocation.display(),
+                    dir: invocation.dir.clone(),
+                    continue_on_error: invocation.continue_on_error,
+                })
+                .collect()
+        };
+
+        Self {
+            directory: plan.directory.clone(),
+            files: plan
+                .files
+                .iter()
+                .map(|(path, content)| PlannedFile {
+                    path: path.clone(),
+                    size: content.len() as u64,
+                    content: content.clone(),
+                })
+                .collect(),
+            pre_render: steps(&plan.pre_render),
+            post_render: steps(&plan.post_render),
+        }
+    }
+}
+
+impl DryRun {
+    /// How many lines of each file to show.
+    const PREVIEW_LINES: usize = 3;
+
+    fn human(&self) -> String {
+        let mut out = String::new();
+
+        writeln!(out, "Would create: {}", self.directory.display())
+            .expect("writing to a string should not fail");
+
+        if !self.files.is_empty() {
+            out.push_str("\nfiles:\n");
+        }
+
+        let mut printed_dirs = Vec::new();
+        for file in &self.files {
+            let parents = file.path.ancestors().skip(1).collect::<Vec<_>>();
+
+            // Print each directory above the file once, outermost first
+            for dir in parents
+                .iter()
+                .rev()
+                .filter(|dir| !dir.as_os_str().is_empty())
+            {
+                if !printed_dirs.contains(dir) {
+                    writeln!(
+                        out,
+                        "{}{}/",
+                        "  ".repeat(dir.components().count()),
+                        dir.file_name().unwrap_or_default().to_string_lossy()
+                    )
+                    .expect("writing to a string should not fail");
+
+                    printed_dirs.push(dir);
+                }
             }
-        })
-        .collect::<Result<_, _>>()?;
 
-    template.render(settings, &course, &context)?;
+            let indent = "  ".repeat(file.path.components().count());
+
+            writeln!(
+                out,
+                "{indent}{} ({})",
+                file.path.file_name().unwrap_or_default().to_string_lossy(),
+                format_size(file.size)
+            )
+            .expect("writing to a string should not fail");
+
+            let mut lines = file.content.lines();
+            for line in lines.by_ref().take(Self::PREVIEW_LINES) {
+                writeln!(out, "{indent}  | {line}").expect("writing to a string should not fail");
+            }
+
+            if lines.next().is_some() {
+                writeln!(out, "{indent}  | ...").expect("writing to a string should not fail");
+            }
+        }
+
+        for (name, steps) in [
+            ("pre-render", &self.pre_render),
+            ("post-render", &self.post_render),
+        ] {
+            if steps.is_empty() {
+                continue;
+            }
+
+            writeln!(out, "\n{name}:").expect("writing to a string should not fail");
+
+            for step in steps {
+                write!(out, "  $ {}", step.command).expect("writing to a string should not fail");
+
+                if let Ok(dir) = step.dir.strip_prefix(&self.directory) {
+                    if !dir.as_os_str().is_empty() {
+                        write!(out, "  (in {})", dir.display())
+                            .expect("writing to a string should not fail");
+                    }
+                } else {
+                    write!(out, "  (in {})", step.dir.display())
+                        .expect("writing to a string should not fail");
+                }
+
+                if step.continue_on_error {
+                    out.push_str("  (may fail)");
+                }
+
+                out.push('\n');
+            }
+        }
+
+        out
+    }
+}
+
+/// Prompt for a value of `parameter` until a valid one is given.
+///
+/// Returns `None` if the answer was left empty and the parameter may be left out.
+fn ask(parameter: &Parameter) -> color_eyre::Result<Option<String>> {
+    let mut question = parameter.name.clone();
+
+    if parameter.kind != Kind::String {
+        write!(question, " ({})", parameter.kind).expect("writing to a string should not fail");
+    }
+
+    if let Some(description) = &parameter.description {
+        write!(question, " - {description}").expect("writing to a string should not fail");
+    }
+
+    if !parameter.choices.is_empty() {
+        write!(question, " [{}]", parameter.choices.join("/"))
+            .expect("writing to a string should not fail");
+    }
+
+    if let Some(default) = &parameter.default {
+        let default = match default {
+            Value::String(s) => s.clone(),
+            Value::Array(items) => items
+                .iter()
+                .map(|item| {
+                    item.as_str()
+                        .map_or_else(|| item.to_string(), ToOwned::to_owned)
+                })
+                .collect::<Vec<_>>()
+                .join(","),
+            value => value.to_string(),
+        };
+
+        write!(question, " (default: {default})").expect("writing to a string should not fail");
+    }
+
+    question.push_str(": ");
+
+    loop {
+        let answer = prompt::ask(&question)?;
+
+        if answer.is_empty() {
+            if parameter.default.is_some() || !parameter.required {
+                return Ok(None);
+            }
+
+            eprintln!("{} is required", parameter.name);
+
+            continue;
+        }
+
+        match parameter.coerce(&answer) {
+            Ok(_) => return Ok(Some(answer)),
+            Err(err) => eprintln!("{err}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::Path, process};
+
+    use super::find_template;
+    use crate::{Course, Settings};
+
+    fn write_template(dir: &Path, description: &str) {
+        fs::create_dir_all(dir).expect("template directory should be creatable");
+        fs::write(
+            dir.join("lab.yml"),
+            format!(
+                "description: {description}\n\
+                 pluralized-name: labs\n\
+                 context-parameters: []\n\
+                 directory-name: lab\n\
+                 files: {{}}\n"
+            ),
+        )
+        .expect("template should be writable");
+    }
+
+    fn description(settings: &Settings, course: &Course, source_code: &str) -> Option<String> {
+        find_template(settings, course, Some(source_code), "lab")
+            .expect("templates should be readable")
+            .and_then(|template| template.description().map(ToOwned::to_owned))
+    }
+
+    #[test]
+    fn course_prefix_falls_back_to_shared_and_global_templates() {
+        let root = env::temp_dir().join(format!("unii-render-{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        let mut settings = Settings {
+            path: root.join("unii"),
+            shared_template_dirs: vec![root.join("shared")],
+            ..Settings::default()
+        };
+        let course = Course::new("CS101".to_owned());
+
+        write_template(&settings.template_dir(), "global");
+        assert_eq!(
+            description(&settings, &course, "CS101").as_deref(),
+            Some("global")
+        );
+
+        write_template(&root.join("shared"), "shared");
+        assert_eq!(
+            description(&settings, &course, "CS101").as_deref(),
+            Some("shared")
+        );
+
+        write_template(&settings.course_template_dir("CS101"), "course");
+        assert_eq!(
+            description(&settings, &course, "CS101").as_deref(),
+            Some("course")
+        );
+
+        settings.shared_template_dirs.clear();
+        assert!(find_template(&settings, &course, Some("MATH135"), "lab").is_err());
 
-    Ok(())
+        fs::remove_dir_all(&root).expect("directory should be removable");
+    }
 }
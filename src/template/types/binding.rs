@@ -0,0 +1,41 @@
+use std::fmt;
+
+use serde_json::Value;
+
+/// A value for a context parameter of a template, and where it came from.
+pub struct Binding {
+    pub name: String,
+    pub value: Value,
+    pub source: Source,
+}
+
+/// Where the value of a context parameter came from, from highest to lowest precedence.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Source {
+    /// A `KEY=VALUE` argument
+    Argument,
+
+    /// An answer to a prompt
+    Prompt,
+
+    /// The `defaults` of the course, under the template's name or `*`
+    Course { code: String, key: String },
+
+    /// The `defaults` of the settings file, under the template's name or `*`
+    Settings { key: String },
+
+    /// The `default` of the parameter in the template itself
+    Template,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Argument => write!(f, "command line"),
+            Self::Prompt => write!(f, "prompt"),
+            Self::Course { code, key } => write!(f, "course {code} (defaults.{key})"),
+            Self::Settings { key } => write!(f, "settings (defaults.{key})"),
+            Self::Template => write!(f, "template default"),
+        }
+    }
+}
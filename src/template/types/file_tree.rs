@@ -6,7 +6,7 @@ use serde::{Deserialize, Deserializer, Serialize};
 #[serde(untagged)]
 pub enum FileTree {
     File(String),
-    Directory(HashMap<String, FileTree>),
+    Directory(HashMap<String, Self>),
 }
 
 impl FileTree {
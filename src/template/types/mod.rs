@@ -1,29 +1,60 @@
+mod binding;
 mod file_tree;
+mod parameter;
+mod plan;
+mod scope;
+mod step;
 
-use std::{collections::HashMap, fs, path::PathBuf, process::Command, string::ToString};
+use std::{collections::HashMap, fs, path::PathBuf, string::ToString};
 
+use chrono::Local;
 use heck::{
     ToKebabCase, ToLowerCamelCase, ToShoutyKebabCase, ToShoutySnakeCase, ToSnakeCase, ToTrainCase,
     ToUpperCamelCase,
 };
+use once_cell::sync::Lazy;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tera::{Context, Tera};
 
-use crate::{Course, Error, Settings};
+pub use self::{
+    binding::{Binding, Source},
+    parameter::{Kind, Parameter},
+    plan::Plan,
+    scope::Scope,
+    step::{Invocation, Run, Step},
+};
+use crate::{Course, Error, Settings, Term};
+
+/// The variables every template gets, which context parameters cannot be named.
+const BUILTIN_VARIABLES: [&str; 8] = [
+    "course", "template", "profile", "today", "now", "index", "term", "week",
+];
 
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct Template {
     #[serde(skip)]
     name: String,
+    #[serde(skip)]
+    scope: Scope,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
     pluralized_name: String,
 
-    context_parameters: Vec<String>,
+    context_parameters: Vec<Parameter>,
     directory_name: String,
     #[serde(deserialize_with = "file_tree::deserialize_into_hashmap")]
     files: HashMap<String, String>,
+    /// A shell command run before the files are written, kept for templates written before
+    /// `pre-render` existed.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     command: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pre_render: Vec<Step>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    post_render: Vec<Step>,
 }
 
 impl Template {
@@ -31,16 +62,22 @@ impl Template {
         settings: &Settings,
         name: String,
         pluralized_name: Option<String>,
+        description: Option<String>,
+        scope: Scope,
     ) -> color_eyre::Result<Self> {
         let pluralized_name = pluralized_name.unwrap_or_else(|| format!("{name}s"));
 
         let template = Self {
             name,
+            scope,
+            description,
             pluralized_name,
             context_parameters: Vec::new(),
             directory_name: String::new(),
             files: HashMap::new(),
             command: String::new(),
+            pre_render: Vec::new(),
+            post_render: Vec::new(),
         };
 
         template.create(settings)?;
@@ -55,7 +92,7 @@ impl Template {
             Err(Error::TemplateAlreadyExists(self.name.clone()))?;
         }
 
-        fs::create_dir_all(settings.template_dir())?;
+        fs::create_dir_all(self.scope.dir(settings))?;
 
         self.write(settings)
     }
@@ -66,12 +103,12 @@ impl Template {
         Ok(())
     }
 
-    pub fn open(
+    pub fn open_in(
         settings: &Settings,
-        source: Option<&Course>,
+        scope: Scope,
         name: &str,
     ) -> color_eyre::Result<Option<Self>> {
-        let path = settings.template_path(source, name);
+        let path = scope.path(settings, name);
 
         if !path.exists() {
             return Ok(None);
@@ -79,17 +116,75 @@ impl Template {
 
         let yaml = fs::read_to_string(path)?;
 
-        Ok(Some(serde_yaml::from_str(&yaml)?))
+        let template = Self {
+            name: name.to_owned(),
+            scope,
+            ..serde_yaml::from_str(&yaml)?
+        };
+
+        template.validate()?;
+
+        Ok(Some(template))
+    }
+
+    /// Check the parts of the template which its YAML schema cannot.
+    fn validate(&self) -> Result<(), Error> {
+        for parameter in &self.context_parameters {
+            if BUILTIN_VARIABLES.contains(&parameter.name.as_str()) {
+                Err(Error::TemplateInvalid(
+                    self.name.clone(),
+                    format!(
+                        "context parameter {} has the same name as a built-in variable",
+                        parameter.name
+                    ),
+                ))?;
+            }
+
+            parameter.validate().map_err(|reason| {
+                Error::TemplateInvalid(
+                    self.name.clone(),
+                    format!("context parameter {}: {reason}", parameter.name),
+                )
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Find the template called `name` to use with `course`, searching the course's templates,
+    /// then the shared template directories, then the global templates.
+    pub fn resolve(
+        settings: &Settings,
+        course: Option<&Course>,
+        name: &str,
+    ) -> color_eyre::Result<Option<Self>> {
+        Self::candidates(settings, course, name)
+            .into_iter()
+            .next()
+            .map_or(Ok(None), |scope| Self::open_in(settings, scope, name))
+    }
+
+    /// Every scope containing a template called `name`, in the order [`Template::resolve`] tries
+    /// them.
+    pub fn candidates(settings: &Settings, course: Option<&Course>, name: &str) -> Vec<Scope> {
+        Scope::search_order(settings, course.map(Course::code))
+            .into_iter()
+            .filter(|scope| scope.path(settings, name).is_file())
+            .collect()
     }
 
-    pub fn render(
+    /// Render the directory name, files and steps of a new item in `course`, without writing
+    /// anything.
+    pub fn plan(
         &self,
         settings: &Settings,
         course: &Course,
-        context: &HashMap<String, Value>,
-    ) -> color_eyre::Result<()> {
+        context_values: &HashMap<String, Value>,
+    ) -> color_eyre::Result<Plan> {
         let mut tera = Self::tera();
-        let context = Context::from_serialize(context)?;
+        let mut context = Context::from_serialize(context_values)?;
+        context.extend(self.builtin_context(settings, course)?);
+
         let mut render = |s: &str| tera.render_str(s, &context);
 
         let directory_name = render(&self.directory_name)?;
@@ -103,45 +198,118 @@ impl Template {
             Err(Error::RenderAlreadyExists(directory_name))?;
         }
 
-        fs::create_dir_all(&directory)?;
-
-        let command = render(&self.command)?;
+        let legacy_command =
+            (!self.command.trim().is_empty()).then(|| Step::new(Run::Shell(self.command.clone())));
 
-        let output = Command::new("sh")
-            .arg("-c")
-            .arg(&command)
-            .current_dir(&directory)
-            .output()?;
+        let pre_render = legacy_command
+            .iter()
+            .chain(&self.pre_render)
+            .map(|step| step.prepare(&directory, &mut render))
+            .collect::<color_eyre::Result<Vec<_>>>()?;
 
-        if !output.status.success() {
-            Err(Error::TemplateCommandFailed(
-                command,
-                String::from_utf8_lossy(&output.stderr).to_string(),
-            ))?;
-        }
+        let post_render = self
+            .post_render
+            .iter()
+            .map(|step| step.prepare(&directory, &mut render))
+            .collect::<color_eyre::Result<Vec<_>>>()?;
 
-        let rendered_files = self
+        let mut files = self
             .files
             .iter()
-            .map(|(path, content)| {
-                let rendered_path = render(path)?;
-                let rendered_content = render(content)?;
-
-                Ok((rendered_path, rendered_content))
-            })
+            .map(|(path, content)| Ok((PathBuf::from(render(path)?), render(content)?)))
             .collect::<color_eyre::Result<Vec<_>>>()?;
+        files.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        Ok(Plan {
+            directory,
+            files,
+            pre_render,
+            post_render,
+        })
+    }
 
-        for (path, content) in rendered_files {
-            let full_path = directory.join(path);
+    /// The variables available to every template, named in [`BUILTIN_VARIABLES`].
+    ///
+    /// Keys use underscores rather than the dashes of `course.yml`, since Tera's dot syntax
+    /// reads `course.final-grade` as a subtraction.
+    fn builtin_context(&self, settings: &Settings, course: &Course) -> color_eyre::Result<Context> {
+        let mut context = Context::new();
+        let now = Local::now();
+
+        let mut course_value = match serde_json::to_value(course)? {
+            Value::Object(fields) => Value::Object(
+                fields
+                    .into_iter()
+                    .map(|(key, value)| (key.to_snake_case(), value))
+                    .collect(),
+            ),
+            value => value,
+        };
+        course_value["code"] = Value::String(course.code().to_owned());
+
+        context.insert("course", &course_value);
+        context.insert(
+            "template",
+            &serde_json::json!({
+                "name": self.name,
+                "pluralized_name": self.pluralized_name,
+                "scope": self.scope.to_string(),
+            }),
+        );
+        let mut profile = settings.profile.clone();
+        profile.extend(course.profile.clone());
+        context.insert("profile", &profile);
+
+        context.insert("today", &now.date_naive());
+        context.insert("now", &now.format("%Y-%m-%dT%H:%M:%S%:z").to_string());
+        context.insert("index", &self.next_index(settings, course)?);
+
+        let course_term = match &course.term {
+            Some(name) => Term::open(settings, name)?,
+            None => Term::current(settings)?,
+        };
 
-            if let Some(parent) = full_path.parent() {
-                fs::create_dir_all(parent)?;
-            }
+        if let Some(term) = course_term {
+            context.insert("week", &term.week(now.date_naive()));
+            context.insert(
+                "term",
+                &serde_json::json!({
+                    "name": term.name(),
+                    "start": term.start,
+                    "end": term.end,
+                }),
+            );
+        }
 
-            fs::write(full_path, content)?;
+        Ok(context)
+    }
+
+    /// One more than the highest number in the names of the items already rendered into
+    /// `course`, or 1 if there are none.
+    pub fn next_index(&self, settings: &Settings, course: &Course) -> color_eyre::Result<u64> {
+        static NUMBER: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"\d+").expect("regex should be valid"));
+
+        let dir = course.dir(settings).join(&self.pluralized_name);
+
+        if !dir.is_dir() {
+            return Ok(1);
         }
 
-        Ok(())
+        let mut highest = 0;
+        for entry in fs::read_dir(dir)? {
+            let name = entry?.file_name();
+
+            if let Some(number) = NUMBER
+                .find_iter(&name.to_string_lossy())
+                .last()
+                .and_then(|number| number.as_str().parse::<u64>().ok())
+            {
+                highest = highest.max(number);
+            }
+        }
+
+        Ok(highest + 1)
     }
 
     fn tera() -> Tera {
@@ -206,13 +374,47 @@ impl Template {
             }
         }
 
+        tt.register_filter("pad", |value: &Value, args: &HashMap<String, Value>| {
+            let value = match value {
+                Value::String(s) => s.clone(),
+                value => value.to_string(),
+            };
+            let width = match args.get("width") {
+                Some(width) => tera::from_value::<usize>(width.clone())?,
+                None => 2,
+            };
+            let fill = match args.get("with") {
+                Some(fill) => tera::from_value::<char>(fill.clone())?,
+                None => '0',
+            };
+
+            let padding = width.saturating_sub(value.chars().count());
+
+            Ok(Value::String(
+                std::iter::repeat(fill)
+                    .take(padding)
+                    .chain(value.chars())
+                    .collect(),
+            ))
+        });
+
         tt
     }
 
-    pub fn all(
+    /// All templates stored in `scope`.
+    pub fn all_in(
         settings: &Settings,
+        scope: Scope,
     ) -> color_eyre::Result<impl Iterator<Item = color_eyre::Result<Self>> + '_> {
-        Ok(fs::read_dir(settings.template_dir())?.filter_map(|entry| {
+        let dir = scope.dir(settings);
+
+        let entries = if dir.exists() {
+            Some(fs::read_dir(dir)?)
+        } else {
+            None
+        };
+
+        Ok(entries.into_iter().flatten().filter_map(move |entry| {
             let path = match entry {
                 Ok(entry) => entry.path(),
                 Err(err) => return Some(Err(err.into())),
@@ -227,30 +429,227 @@ impl Template {
                 None => return None,
             };
 
-            if !path.extension().map(|ext| ext == "yml").unwrap_or_default() {
+            if path.extension().map_or(true, |ext| ext != "yml") {
                 return None;
             }
 
             Some(
-                Self::open(settings, None, &name) // TODO: add source
+                Self::open_in(settings, scope.clone(), &name)
                     .map(|option| option.expect("template should exist")),
             )
         }))
     }
 
     pub fn path(&self, settings: &Settings) -> PathBuf {
-        settings
-            .path
-            .join("templates")
-            .join(&self.name)
-            .with_extension("yml")
+        self.scope.path(settings, &self.name)
     }
 
     pub fn name(&self) -> &str {
         &self.name
     }
 
-    pub fn context_parameters(&self) -> &[String] {
+    pub const fn scope(&self) -> &Scope {
+        &self.scope
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn directory_name(&self) -> &str {
+        &self.directory_name
+    }
+
+    pub fn pluralized_name(&self) -> &str {
+        &self.pluralized_name
+    }
+
+    pub fn context_parameters(&self) -> &[Parameter] {
         &self.context_parameters
     }
+
+    /// Values for the context parameters which were not given, from the `defaults` of `course`
+    /// and then of the settings file, under the template's name and then `*`.
+    ///
+    /// Defaults left empty (`key:` without a value) are skipped rather than bound to `null`.
+    pub fn preset_bindings(
+        &self,
+        settings: &Settings,
+        course: &Course,
+    ) -> Result<Vec<Binding>, serde_json::Error> {
+        let keys = [self.name.as_str(), "*"];
+
+        let levels = keys
+            .iter()
+            .map(|key| {
+                (
+                    course.defaults.get(*key),
+                    Source::Course {
+                        code: course.code().to_owned(),
+                        key: (*key).to_owned(),
+                    },
+                )
+            })
+            .chain(keys.iter().map(|key| {
+                (
+                    settings.defaults.get(*key),
+                    Source::Settings {
+                        key: (*key).to_owned(),
+                    },
+                )
+            }))
+            .filter_map(|(defaults, source)| defaults.map(|defaults| (defaults, source)))
+            .collect::<Vec<_>>();
+
+        self.context_parameters
+            .iter()
+            .filter_map(|parameter| {
+                levels.iter().find_map(|(defaults, source)| {
+                    defaults
+                        .get(&parameter.name)
+                        .filter(|value| !value.is_null())
+                        .map(|value| {
+                            Ok(Binding {
+                                name: parameter.name.clone(),
+                                value: serde_json::to_value(value)?,
+                                source: source.clone(),
+                            })
+                        })
+                })
+            })
+            .collect()
+    }
+
+    /// Validate the values given for the template and fill in the defaults of its parameters.
+    ///
+    /// Optional parameters without a value or default are left out of the context.
+    pub fn context(&self, bindings: Vec<Binding>) -> Result<Vec<Binding>, Error> {
+        let mut context = Vec::<Binding>::new();
+
+        for binding in bindings {
+            if context.iter().any(|bound| bound.name == binding.name) {
+                continue;
+            }
+
+            let parameter = self
+                .context_parameters
+                .iter()
+                .find(|parameter| parameter.name == binding.name)
+                .ok_or(Error::TemplateContextParameterDoesNotExist(binding.name))?;
+
+            context.push(Binding {
+                name: parameter.name.clone(),
+                value: parameter.coerce_value(&binding.value)?,
+                source: binding.source,
+            });
+        }
+
+        for parameter in &self.context_parameters {
+            if context.iter().any(|bound| bound.name == parameter.name) {
+                continue;
+            }
+
+            if let Some(value) = &parameter.default {
+                context.push(Binding {
+                    name: parameter.name.clone(),
+                    value: parameter.coerce_value(value)?,
+                    source: Source::Template,
+                });
+            } else if parameter.required {
+                Err(Error::TemplateContextParameterMissing(
+                    parameter.name.clone(),
+                ))?;
+            }
+        }
+
+        Ok(context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::{Binding, Run, Source, Template};
+
+    #[test]
+    fn deserializes_the_legacy_command_next_to_steps() {
+        let template: Template = serde_yaml::from_str(
+            "
+            pluralized-name: labs
+            context-parameters: []
+            directory-name: lab
+            files: {}
+            command: git init
+            pre-render:
+              - [mkdir, -p, src]
+            post-render:
+              - run: git add .
+                continue-on-error: true
+            ",
+        )
+        .expect("template should parse");
+
+        assert_eq!(template.command, "git init");
+        assert!(matches!(&template.pre_render[0].run, Run::Argv(argv) if argv.len() == 3));
+        assert!(template.post_render[0].continue_on_error);
+    }
+
+    #[test]
+    fn command_and_steps_are_optional() {
+        let template: Template = serde_yaml::from_str(
+            "
+            pluralized-name: labs
+            context-parameters: []
+            directory-name: lab
+            files: {}
+            ",
+        )
+        .expect("template should parse");
+
+        assert!(template.command.is_empty());
+        assert!(template.pre_render.is_empty() && template.post_render.is_empty());
+    }
+
+    fn template_with_parameters(parameters: &str) -> Template {
+        serde_yaml::from_str(&format!(
+            "
+            pluralized-name: labs
+            context-parameters: {parameters}
+            directory-name: lab
+            files: {{}}
+            "
+        ))
+        .expect("template should parse")
+    }
+
+    #[test]
+    fn optional_parameters_without_a_value_are_left_out() {
+        let template = template_with_parameters(
+            "[title, {name: number, type: int, default: 1}, {name: due, type: date}]",
+        );
+
+        let context = template
+            .context(vec![Binding {
+                name: "title".to_owned(),
+                value: Value::String("Intro".to_owned()),
+                source: Source::Argument,
+            }])
+            .expect("context should be complete");
+
+        assert_eq!(
+            context
+                .iter()
+                .map(|binding| (binding.name.as_str(), &binding.value))
+                .collect::<Vec<_>>(),
+            [("title", &json!("Intro")), ("number", &json!(1))]
+        );
+    }
+
+    #[test]
+    fn required_parameters_without_a_value_are_an_error() {
+        let template = template_with_parameters("[{name: title, required: true}]");
+
+        assert!(template.context(Vec::new()).is_err());
+    }
 }
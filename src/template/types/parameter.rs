@@ -0,0 +1,461 @@
+use std::fmt;
+
+use chrono::NaiveDate;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::Error;
+
+/// A context parameter of a template.
+///
+/// Declared either as just its name (an optional string) or as a map with its name and any of
+/// `type`, `default`, `required`, `description`, `pattern` and `choices`.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(from = "Declaration", into = "Declaration")]
+pub struct Parameter {
+    pub name: String,
+    pub kind: Kind,
+    pub default: Option<Value>,
+    pub required: bool,
+    pub description: Option<String>,
+    /// A regex which the whole value (or every item of a list) has to match.
+    pub pattern: Option<String>,
+    /// The allowed values of a `choice` parameter.
+    pub choices: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Kind {
+    #[default]
+    String,
+    Int,
+    Float,
+    Bool,
+    Date,
+    #[serde(alias = "enum")]
+    Choice,
+    List,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::String => "string",
+            Self::Int => "int",
+            Self::Float => "float",
+            Self::Bool => "bool",
+            Self::Date => "date",
+            Self::Choice => "choice",
+            Self::List => "list",
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum Declaration {
+    Name(String),
+    Full {
+        name: String,
+        #[serde(default, rename = "type")]
+        kind: Kind,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        default: Option<Value>,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        required: bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pattern: Option<String>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        choices: Vec<String>,
+    },
+}
+
+impl From<Declaration> for Parameter {
+    fn from(declaration: Declaration) -> Self {
+        match declaration {
+            Declaration::Name(name) => Self::new(name),
+            Declaration::Full {
+                name,
+                kind,
+                default,
+                required,
+                description,
+                pattern,
+                choices,
+            } => Self {
+                name,
+                kind,
+                default,
+                required,
+                description,
+                pattern,
+                choices,
+            },
+        }
+    }
+}
+
+impl From<Parameter> for Declaration {
+    fn from(parameter: Parameter) -> Self {
+        let Parameter {
+            name,
+            kind,
+            default,
+            required,
+            description,
+            pattern,
+            choices,
+        } = parameter;
+
+        if kind == Kind::String
+            && default.is_none()
+            && !required
+            && description.is_none()
+            && pattern.is_none()
+            && choices.is_empty()
+        {
+            return Self::Name(name);
+        }
+
+        Self::Full {
+            name,
+            kind,
+            default,
+            required,
+            description,
+            pattern,
+            choices,
+        }
+    }
+}
+
+impl Parameter {
+    pub const fn new(name: String) -> Self {
+        Self {
+            name,
+            kind: Kind::String,
+            default: None,
+            required: false,
+            description: None,
+            pattern: None,
+            choices: Vec::new(),
+        }
+    }
+
+    /// Check the declaration itself, so that mistakes in a template show up when it is loaded
+    /// rather than only once a value is given.
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(pattern) = &self.pattern {
+            if !matches!(self.kind, Kind::String | Kind::List) {
+                return Err(format!(
+                    "`pattern` only applies to string and list parameters, not {}",
+                    self.kind
+                ));
+            }
+
+            Regex::new(&format!("^(?:{pattern})$"))
+                .map_err(|err| format!("invalid pattern: {err}"))?;
+        }
+
+        match (self.kind, self.choices.is_empty()) {
+            (Kind::Choice, true) => Err("a choice parameter needs at least one choice".to_owned()),
+            (kind, false) if kind != Kind::Choice => Err(format!(
+                "`choices` only applies to choice parameters, not {kind}"
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check `raw` against the parameter's type, pattern and choices, and convert it into the
+    /// value templates see.
+    pub fn coerce(&self, raw: &str) -> Result<Value, Error> {
+        let invalid =
+            |reason: String| Error::TemplateContextParameterInvalid(self.name.clone(), reason);
+
+        let pattern = self
+            .pattern
+            .as_ref()
+            .map(|pattern| {
+                Regex::new(&format!("^(?:{pattern})$"))
+                    .map_err(|err| invalid(format!("invalid pattern in template: {err}")))
+            })
+            .transpose()?;
+
+        let check_pattern = |value: &str| match &pattern {
+            Some(regex) if !regex.is_match(value) => Err(invalid(format!(
+                "{value:?} does not match pattern {}",
+                self.pattern.as_deref().unwrap_or_default()
+            ))),
+            _ => Ok(()),
+        };
+
+        let raw = raw.trim();
+
+        Ok(match self.kind {
+            Kind::String => {
+                check_pattern(raw)?;
+
+                Value::String(raw.to_owned())
+            }
+            Kind::Int => raw
+                .parse::<i64>()
+                .map(Value::from)
+                .map_err(|_| invalid(format!("{raw:?} is not a whole number")))?,
+            Kind::Float => raw
+                .parse::<f64>()
+                .ok()
+                .filter(|float| float.is_finite())
+                .map(Value::from)
+                .ok_or_else(|| invalid(format!("{raw:?} is not a number")))?,
+            Kind::Bool => match raw.to_lowercase().as_str() {
+                "true" | "yes" | "y" | "on" | "1" => Value::Bool(true),
+                "false" | "no" | "n" | "off" | "0" => Value::Bool(false),
+                _ => Err(invalid(format!("{raw:?} is not true or false")))?,
+            },
+            Kind::Date => NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+                .map(|date| Value::String(date.to_string()))
+                .map_err(|_| invalid(format!("{raw:?} is not a date (YYYY-MM-DD)")))?,
+            Kind::Choice => {
+                if !self.choices.iter().any(|choice| choice == raw) {
+                    Err(invalid(format!(
+                        "{raw:?} is not one of: {}",
+                        self.choices.join(", ")
+                    )))?;
+                }
+
+                Value::String(raw.to_owned())
+            }
+            Kind::List => Value::Array(
+                raw.split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(|item| check_pattern(item).map(|()| Value::String(item.to_owned())))
+                    .collect::<Result<_, _>>()?,
+            ),
+        })
+    }
+
+    /// Check a value given as YAML or JSON rather than as text, like a default.
+    pub fn coerce_value(&self, value: &Value) -> Result<Value, Error> {
+        match value {
+            Value::Null => Err(Error::TemplateContextParameterMissing(self.name.clone())),
+            Value::String(raw) => self.coerce(raw),
+            Value::Array(items) if self.kind == Kind::List => {
+                let raw = items
+                    .iter()
+                    .map(|item| {
+                        item.as_str()
+                            .map_or_else(|| item.to_string(), ToOwned::to_owned)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                self.coerce(&raw)
+            }
+            value => self.coerce(&value.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Parameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+
+        if self.kind != Kind::String {
+            write!(f, ": {}", self.kind)?;
+        }
+
+        if self.required {
+            write!(f, " (required)")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::{Kind, Parameter};
+
+    fn parameter(kind: Kind) -> Parameter {
+        Parameter {
+            kind,
+            ..Parameter::new("number".to_owned())
+        }
+    }
+
+    fn coerce(parameter: &Parameter, raw: &str) -> Option<Value> {
+        parameter.coerce(raw).ok()
+    }
+
+    #[test]
+    fn strings() {
+        let mut parameter = parameter(Kind::String);
+
+        assert_eq!(coerce(&parameter, " a b "), Some(json!("a b")));
+
+        parameter.pattern = Some("[a-z]+".to_owned());
+
+        assert_eq!(coerce(&parameter, "abc"), Some(json!("abc")));
+        assert_eq!(coerce(&parameter, "abc1"), None);
+    }
+
+    #[test]
+    fn ints() {
+        let parameter = parameter(Kind::Int);
+
+        assert_eq!(coerce(&parameter, "42"), Some(json!(42)));
+        assert_eq!(coerce(&parameter, "-3"), Some(json!(-3)));
+        assert_eq!(coerce(&parameter, "4.2"), None);
+        assert_eq!(coerce(&parameter, "four"), None);
+    }
+
+    #[test]
+    fn floats() {
+        let parameter = parameter(Kind::Float);
+
+        assert_eq!(coerce(&parameter, "4.5"), Some(json!(4.5)));
+        assert_eq!(coerce(&parameter, "4"), Some(json!(4.0)));
+        assert_eq!(coerce(&parameter, "four"), None);
+
+        for raw in ["nan", "NaN", "inf", "-inf", "infinity"] {
+            assert_eq!(coerce(&parameter, raw), None, "{raw}");
+        }
+    }
+
+    #[test]
+    fn bools() {
+        let parameter = parameter(Kind::Bool);
+
+        for raw in ["true", "Yes", "y", "on", "1"] {
+            assert_eq!(coerce(&parameter, raw), Some(json!(true)), "{raw}");
+        }
+
+        for raw in ["false", "NO", "n", "off", "0"] {
+            assert_eq!(coerce(&parameter, raw), Some(json!(false)), "{raw}");
+        }
+
+        assert_eq!(coerce(&parameter, "maybe"), None);
+    }
+
+    #[test]
+    fn dates() {
+        let parameter = parameter(Kind::Date);
+
+        assert_eq!(coerce(&parameter, "2023-09-06"), Some(json!("2023-09-06")));
+        assert_eq!(coerce(&parameter, "2023-02-30"), None);
+        assert_eq!(coerce(&parameter, "06/09/2023"), None);
+    }
+
+    #[test]
+    fn choices() {
+        let parameter = Parameter {
+            choices: vec!["lab".to_owned(), "lecture".to_owned()],
+            ..parameter(Kind::Choice)
+        };
+
+        assert_eq!(coerce(&parameter, "lab"), Some(json!("lab")));
+        assert_eq!(coerce(&parameter, "Lab"), None);
+        assert_eq!(coerce(&parameter, "tutorial"), None);
+    }
+
+    #[test]
+    fn lists() {
+        let mut parameter = parameter(Kind::List);
+
+        assert_eq!(coerce(&parameter, "a, b,,c "), Some(json!(["a", "b", "c"])));
+        assert_eq!(coerce(&parameter, ""), Some(json!([])));
+
+        parameter.pattern = Some(r"q\d+".to_owned());
+
+        assert_eq!(coerce(&parameter, "q1,q2"), Some(json!(["q1", "q2"])));
+        assert_eq!(coerce(&parameter, "q1,x"), None);
+    }
+
+    #[test]
+    fn coerces_yaml_values() {
+        assert_eq!(
+            parameter(Kind::Int).coerce_value(&json!(3)).ok(),
+            Some(json!(3))
+        );
+        assert_eq!(
+            parameter(Kind::Bool).coerce_value(&json!(true)).ok(),
+            Some(json!(true))
+        );
+        assert_eq!(
+            parameter(Kind::List).coerce_value(&json!(["a", 1])).ok(),
+            Some(json!(["a", "1"]))
+        );
+        assert_eq!(
+            parameter(Kind::String).coerce_value(&json!(12)).ok(),
+            Some(json!("12"))
+        );
+        assert!(parameter(Kind::String).coerce_value(&Value::Null).is_err());
+    }
+
+    #[test]
+    fn validates_declarations() {
+        assert!(parameter(Kind::String).validate().is_ok());
+        assert!(Parameter {
+            pattern: Some("[a-z]+".to_owned()),
+            ..parameter(Kind::List)
+        }
+        .validate()
+        .is_ok());
+
+        for kind in [Kind::Int, Kind::Float, Kind::Bool, Kind::Date, Kind::Choice] {
+            let parameter = Parameter {
+                pattern: Some(".*".to_owned()),
+                choices: vec!["a".to_owned()],
+                ..parameter(kind)
+            };
+
+            assert!(parameter.validate().is_err(), "pattern on {kind}");
+        }
+
+        assert!(Parameter {
+            pattern: Some("(".to_owned()),
+            ..parameter(Kind::String)
+        }
+        .validate()
+        .is_err());
+        assert!(parameter(Kind::Choice).validate().is_err());
+        assert!(Parameter {
+            choices: vec!["a".to_owned()],
+            ..parameter(Kind::String)
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn deserializes_both_forms() {
+        let parameters: Vec<Parameter> = serde_yaml::from_str(
+            "
+            - title
+            - name: number
+              type: int
+              default: 1
+              required: true
+            - name: kind
+              type: enum
+              choices: [lab, lecture]
+            ",
+        )
+        .expect("parameters should parse");
+
+        assert_eq!(parameters[0].name, "title");
+        assert!(parameters[0].kind == Kind::String);
+        assert!(parameters[1].kind == Kind::Int);
+        assert_eq!(parameters[1].default, Some(json!(1)));
+        assert!(parameters[1].required);
+        assert!(parameters[2].kind == Kind::Choice);
+        assert_eq!(parameters[2].choices, ["lab", "lecture"]);
+    }
+}
@@ -0,0 +1,113 @@
+use std::{fs, path::PathBuf};
+
+use super::Invocation;
+
+/// Everything rendering a template would do, worked out before anything touches the disk.
+pub struct Plan {
+    /// The directory of the new item.
+    pub directory: PathBuf,
+    /// The files to write, relative to `directory`, sorted by path.
+    pub files: Vec<(PathBuf, String)>,
+    pub pre_render: Vec<Invocation>,
+    pub post_render: Vec<Invocation>,
+}
+
+impl Plan {
+    /// Create the item: run the pre-render steps, write the files, then run the post-render steps.
+    ///
+    /// If a pre-render step or writing a file fails, the item's directory is removed again (unless
+    /// it existed before), so that no half-made item is left behind. Once the files are written
+    /// they are kept, even if a post-render step fails.
+    pub fn apply(&self) -> color_eyre::Result<()> {
+        let existed = self.directory.exists();
+
+        fs::create_dir_all(&self.directory)?;
+
+        if let Err(err) = self.create() {
+            if !existed {
+                if let Err(remove_err) = fs::remove_dir_all(&self.directory) {
+                    eprintln!(
+                        "warning: could not remove {}: {remove_err}",
+                        self.directory.display()
+                    );
+                }
+            }
+
+            return Err(err);
+        }
+
+        for invocation in &self.post_render {
+            invocation.run()?;
+        }
+
+        Ok(())
+    }
+
+    /// Run the pre-render steps and write the files.
+    fn create(&self) -> color_eyre::Result<()> {
+        for invocation in &self.pre_render {
+            invocation.run()?;
+        }
+
+        for (path, content) in &self.files {
+            let full_path = self.directory.join(path);
+
+            if let Some(parent) = full_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            fs::write(full_path, content)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::PathBuf, process};
+
+    use super::{Invocation, Plan};
+    use crate::template::types::Run;
+
+    fn plan(name: &str, pre_render: &[&str]) -> Plan {
+        let directory = env::temp_dir().join(format!("unii-plan-{}-{name}", process::id()));
+        let _ = fs::remove_dir_all(&directory);
+
+        Plan {
+            pre_render: vec![Invocation {
+                run: Run::Argv(pre_render.iter().map(|&arg| arg.to_owned()).collect()),
+                dir: directory.clone(),
+                env: Vec::new(),
+                continue_on_error: false,
+            }],
+            directory,
+            files: vec![(PathBuf::from("src/main.rs"), "fn main() {}".to_owned())],
+            post_render: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn writes_the_files() {
+        let plan = plan("ok", &["true"]);
+
+        plan.apply().expect("plan should apply");
+
+        assert_eq!(
+            fs::read_to_string(plan.directory.join("src/main.rs"))
+                .ok()
+                .as_deref(),
+            Some("fn main() {}")
+        );
+
+        fs::remove_dir_all(&plan.directory).expect("directory should be removable");
+    }
+
+    #[test]
+    fn removes_the_item_when_a_pre_render_step_fails() {
+        let plan = plan("failed", &["false"]);
+
+        assert!(plan.apply().is_err());
+        assert!(!plan.directory.exists());
+    }
+}
@@ -0,0 +1,56 @@
+use std::{fmt, path::PathBuf};
+
+use crate::Settings;
+
+/// Where a template is stored.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub enum Scope {
+    /// The templates of a single course
+    Course(String),
+
+    /// One of the shared template directories from the settings file
+    Shared(PathBuf),
+
+    /// The global templates
+    #[default]
+    Global,
+}
+
+impl Scope {
+    /// The scopes to search for templates used with `course`, from highest to lowest precedence.
+    pub fn search_order(settings: &Settings, course: Option<&str>) -> Vec<Self> {
+        course
+            .map(|code| Self::Course(code.to_owned()))
+            .into_iter()
+            .chain(
+                settings
+                    .shared_template_dirs()
+                    .into_iter()
+                    .map(Self::Shared),
+            )
+            .chain([Self::Global])
+            .collect()
+    }
+
+    pub fn dir(&self, settings: &Settings) -> PathBuf {
+        match self {
+            Self::Course(code) => settings.course_template_dir(code),
+            Self::Shared(dir) => dir.clone(),
+            Self::Global => settings.template_dir(),
+        }
+    }
+
+    pub fn path(&self, settings: &Settings, name: &str) -> PathBuf {
+        self.dir(settings).join(name).with_extension("yml")
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Course(code) => write!(f, "{code}"),
+            Self::Shared(_) => write!(f, "shared"),
+            Self::Global => write!(f, "global"),
+        }
+    }
+}
@@ -0,0 +1,275 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Component, Path, PathBuf},
+    process::Command,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+/// A command run before or after the files of a template are written.
+///
+/// Declared either as just the command (a shell string or an argv list) or as a map with `run`
+/// and any of `dir`, `env` and `continue-on-error`. Every string is rendered like the files.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(from = "Declaration", into = "Declaration")]
+pub struct Step {
+    pub run: Run,
+    /// The directory to run in, relative to the rendered item and inside it.
+    pub dir: Option<String>,
+    pub env: BTreeMap<String, String>,
+    pub continue_on_error: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum Run {
+    /// Run through `sh -c`
+    Shell(String),
+
+    /// Run the program directly, without a shell
+    Argv(Vec<String>),
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum Declaration {
+    Run(Run),
+    #[serde(rename_all = "kebab-case")]
+    Full {
+        run: Run,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        dir: Option<String>,
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        env: BTreeMap<String, String>,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        continue_on_error: bool,
+    },
+}
+
+impl From<Declaration> for Step {
+    fn from(declaration: Declaration) -> Self {
+        match declaration {
+            Declaration::Run(run) => Self::new(run),
+            Declaration::Full {
+                run,
+                dir,
+                env,
+                continue_on_error,
+            } => Self {
+                run,
+                dir,
+                env,
+                continue_on_error,
+            },
+        }
+    }
+}
+
+impl From<Step> for Declaration {
+    fn from(step: Step) -> Self {
+        if step.dir.is_none() && step.env.is_empty() && !step.continue_on_error {
+            return Self::Run(step.run);
+        }
+
+        Self::Full {
+            run: step.run,
+            dir: step.dir,
+            env: step.env,
+            continue_on_error: step.continue_on_error,
+        }
+    }
+}
+
+/// A step with its strings rendered, ready to run.
+pub struct Invocation {
+    pub run: Run,
+    pub dir: PathBuf,
+    pub env: Vec<(String, String)>,
+    pub continue_on_error: bool,
+}
+
+impl Step {
+    pub const fn new(run: Run) -> Self {
+        Self {
+            run,
+            dir: None,
+            env: BTreeMap::new(),
+            continue_on_error: false,
+        }
+    }
+
+    /// Render the strings of the step for an item rendered into `item_dir`.
+    pub fn prepare(
+        &self,
+        item_dir: &Path,
+        render: &mut impl FnMut(&str) -> tera::Result<String>,
+    ) -> color_eyre::Result<Invocation> {
+        let run = match &self.run {
+            Run::Shell(command) => Run::Shell(render(command)?),
+            Run::Argv(argv) => Run::Argv(
+                argv.iter()
+                    .map(|arg| render(arg))
+                    .collect::<tera::Result<_>>()?,
+            ),
+        };
+
+        let dir = match &self.dir {
+            Some(dir) => {
+                let dir = render(dir)?;
+
+                if !Path::new(&dir)
+                    .components()
+                    .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+                {
+                    Err(Error::TemplateStepDirOutsideItem(dir.clone()))?;
+                }
+
+                item_dir.join(dir)
+            }
+            None => item_dir.to_owned(),
+        };
+
+        let env = self
+            .env
+            .iter()
+            .map(|(key, value)| Ok((key.clone(), render(value)?)))
+            .collect::<tera::Result<_>>()?;
+
+        Ok(Invocation {
+            run,
+            dir,
+            env,
+            continue_on_error: self.continue_on_error,
+        })
+    }
+}
+
+impl Invocation {
+    /// The command as it would be typed into a shell.
+    pub fn display(&self) -> String {
+        match &self.run {
+            Run::Shell(command) => command.clone(),
+            Run::Argv(argv) => argv
+                .iter()
+                .map(|arg| {
+                    if arg.is_empty() || arg.contains(char::is_whitespace) {
+                        format!("{arg:?}")
+                    } else {
+                        arg.clone()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+
+    /// Run the step, streaming its output, and fail unless it succeeds or may fail.
+    ///
+    /// The step's directory is created first, since pre-render steps run before any of the
+    /// item's files exist.
+    pub fn run(&self) -> color_eyre::Result<()> {
+        let display = self.display();
+
+        fs::create_dir_all(&self.dir)?;
+
+        let mut command = match &self.run {
+            Run::Shell(command) => {
+                let mut sh = Command::new("sh");
+                sh.arg("-c").arg(command);
+                sh
+            }
+            Run::Argv(argv) => {
+                let (program, rest) = argv.split_first().ok_or(Error::TemplateCommandIsEmpty)?;
+
+                let mut command = Command::new(program);
+                command.args(rest);
+                command
+            }
+        };
+
+        eprintln!("$ {display}");
+
+        let failure = match command
+            .current_dir(&self.dir)
+            .envs(self.env.iter().map(|(key, value)| (key, value)))
+            .status()
+        {
+            Ok(status) if status.success() => return Ok(()),
+            Ok(status) => status.to_string(),
+            Err(err) => err.to_string(),
+        };
+
+        if self.continue_on_error {
+            eprintln!("warning: {display} failed ({failure}), continuing");
+
+            Ok(())
+        } else {
+            Err(Error::TemplateCommandFailed(display, failure))?
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{Run, Step};
+
+    fn prepare(dir: &str) -> color_eyre::Result<std::path::PathBuf> {
+        let step = Step {
+            dir: Some(dir.to_owned()),
+            ..Step::new(Run::Shell("true".to_owned()))
+        };
+
+        step.prepare(Path::new("/item"), &mut |s| Ok(s.to_owned()))
+            .map(|invocation| invocation.dir)
+    }
+
+    #[test]
+    fn deserializes_every_form() {
+        let steps: Vec<Step> = serde_yaml::from_str(
+            "
+            - git init
+            - [git, add, .]
+            - run: make
+              dir: build
+              env:
+                CC: clang
+              continue-on-error: true
+            - run: [echo, hi]
+            ",
+        )
+        .expect("steps should parse");
+
+        assert!(matches!(&steps[0].run, Run::Shell(command) if command == "git init"));
+        assert!(steps[0].dir.is_none() && !steps[0].continue_on_error);
+        assert!(matches!(&steps[1].run, Run::Argv(argv) if argv == &["git", "add", "."]));
+        assert!(matches!(&steps[2].run, Run::Shell(command) if command == "make"));
+        assert_eq!(steps[2].dir.as_deref(), Some("build"));
+        assert_eq!(steps[2].env["CC"], "clang");
+        assert!(steps[2].continue_on_error);
+        assert!(matches!(&steps[3].run, Run::Argv(argv) if argv == &["echo", "hi"]));
+    }
+
+    #[test]
+    fn runs_in_directories_inside_the_item() {
+        assert_eq!(
+            prepare("build").ok(),
+            Some(Path::new("/item/build").to_owned())
+        );
+        assert_eq!(
+            prepare("./a/b").ok(),
+            Some(Path::new("/item/a/b").to_owned())
+        );
+    }
+
+    #[test]
+    fn rejects_directories_outside_the_item() {
+        for dir in ["/abs", "..", "../..", "a/../../b"] {
+            assert!(prepare(dir).is_err(), "{dir} should be rejected");
+        }
+    }
+}
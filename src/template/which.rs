@@ -0,0 +1,64 @@
+use std::{fmt::Write, path::PathBuf};
+
+use clap::Parser;
+use serde::Serialize;
+
+use super::Template;
+use crate::{Course, Error, Format, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    /// The name of the template
+    name: String,
+
+    /// The course to resolve the template for (defaults to the course containing the working
+    /// directory)
+    #[clap(short, long, aliases = ["course", "code"])]
+    course_code: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Candidate {
+    scope: String,
+    path: PathBuf,
+    shadowed: bool,
+}
+
+pub fn run(settings: &Settings, format: Format, args: Args) -> color_eyre::Result<()> {
+    let course = match args.course_code {
+        Some(code) => Some(Course::open(settings, &code)?.ok_or(Error::CourseDoesNotExist(code))?),
+        None => Course::current(settings)?,
+    };
+
+    let candidates = Template::candidates(settings, course.as_ref(), &args.name)
+        .into_iter()
+        .enumerate()
+        .map(|(i, scope)| Candidate {
+            path: scope.path(settings, &args.name),
+            scope: scope.to_string(),
+            shadowed: i > 0,
+        })
+        .collect::<Vec<_>>();
+
+    if candidates.is_empty() {
+        Err(Error::TemplateDoesNotExist(None, args.name))?;
+    }
+
+    format.print(&candidates, |candidates| {
+        let mut out = String::new();
+
+        for candidate in candidates {
+            writeln!(
+                out,
+                "{}{} ({})",
+                if candidate.shadowed { "  shadows " } else { "" },
+                candidate.path.display(),
+                candidate.scope
+            )
+            .expect("writing to a string should not fail");
+        }
+
+        out
+    })
+}
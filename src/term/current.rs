@@ -0,0 +1,39 @@
+use chrono::Local;
+use clap::Parser;
+use serde::Serialize;
+
+use super::Term;
+use crate::{Error, Format, Settings};
+
+#[derive(Parser)]
+pub struct Args {}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Current {
+    name: String,
+    week: Option<i64>,
+    #[serde(flatten)]
+    term: Term,
+}
+
+pub fn run(settings: &Settings, format: Format, _: Args) -> color_eyre::Result<()> {
+    let term = Term::current(settings)?.ok_or(Error::CurrentTermMissing)?;
+
+    let current = Current {
+        name: term.name().to_owned(),
+        week: term.week(Local::now().date_naive()),
+        term,
+    };
+
+    format.print(&current, |current| {
+        let week = current
+            .week
+            .map_or_else(|| "no classes".to_owned(), |week| format!("week {week}"));
+
+        format!(
+            "{} ({} to {}): {week}\n",
+            current.name, current.term.start, current.term.end
+        )
+    })
+}
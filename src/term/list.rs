@@ -0,0 +1,46 @@
+use clap::Parser;
+use serde::Serialize;
+
+use super::Term;
+use crate::{format::Table, Format, Settings};
+
+#[derive(Parser)]
+pub struct Args {}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Listing {
+    name: String,
+    current: bool,
+    #[serde(flatten)]
+    term: Term,
+}
+
+pub fn run(settings: &Settings, format: Format, _: Args) -> color_eyre::Result<()> {
+    let current = Term::current(settings)?.map(|term| term.name().to_owned());
+
+    let listings = Term::all(settings)?
+        .into_iter()
+        .map(|term| Listing {
+            name: term.name().to_owned(),
+            current: current.as_deref() == Some(term.name()),
+            term,
+        })
+        .collect::<Vec<_>>();
+
+    format.print(&listings, |listings| {
+        let mut table = Table::new(vec!["name", "start", "end", "breaks", "current"]);
+
+        for listing in listings {
+            table.push(vec![
+                listing.name.clone(),
+                listing.term.start.to_string(),
+                listing.term.end.to_string(),
+                listing.term.breaks.len().to_string(),
+                if listing.current { "*" } else { "" }.to_owned(),
+            ]);
+        }
+
+        table.to_string()
+    })
+}
@@ -0,0 +1,43 @@
+mod current;
+mod list;
+mod new;
+mod set;
+mod types;
+
+use clap::Parser;
+
+pub use self::types::{week_start, Term};
+
+use crate::{Format, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Parser)]
+enum Command {
+    /// Create a new term
+    #[clap(aliases = ["create", "add"])]
+    New(new::Args),
+
+    /// List all terms
+    #[clap(aliases = ["ls"])]
+    List(list::Args),
+
+    /// Show the current term
+    Current(current::Args),
+
+    /// Set the current term
+    Set(set::Args),
+}
+
+pub fn run(settings: &mut Settings, format: Format, args: Args) -> color_eyre::Result<()> {
+    match args.command {
+        Command::New(args) => new::run(settings, args),
+        Command::List(args) => list::run(settings, format, args),
+        Command::Current(args) => current::run(settings, format, args),
+        Command::Set(args) => set::run(settings, args),
+    }
+}
@@ -0,0 +1,31 @@
+use chrono::NaiveDate;
+use clap::Parser;
+
+use super::Term;
+use crate::Settings;
+
+#[derive(Parser)]
+pub struct Args {
+    /// The name of the term
+    name: String,
+
+    /// The first day of the term (YYYY-MM-DD)
+    #[clap(short, long)]
+    start: NaiveDate,
+
+    /// The last day of the term (YYYY-MM-DD)
+    #[clap(short, long)]
+    end: NaiveDate,
+
+    /// A day in a week with no classes (can be repeated)
+    #[clap(short, long = "break", name = "BREAK")]
+    breaks: Vec<NaiveDate>,
+}
+
+pub fn run(settings: &Settings, args: Args) -> color_eyre::Result<()> {
+    let term = Term::new(settings, args.name, args.start, args.end, args.breaks)?;
+
+    println!("Created term: {}", term.name());
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use clap::Parser;
+
+use super::Term;
+use crate::{Error, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    /// The name of the term (omit to go back to picking the term by today's date)
+    name: Option<String>,
+}
+
+pub fn run(settings: &mut Settings, args: Args) -> color_eyre::Result<()> {
+    if let Some(name) = &args.name {
+        if Term::open(settings, name)?.is_none() {
+            Err(Error::TermDoesNotExist(name.clone()))?;
+        }
+    }
+
+    settings.current_term = args.name;
+    settings.save()?;
+
+    match &settings.current_term {
+        Some(name) => println!("Set current term: {name}"),
+        None => println!("Unset current term"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,237 @@
+use std::{collections::BTreeSet, fs, path::PathBuf};
+
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Settings};
+
+/// An academic term (e.g. a semester) which courses are taken in.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Term {
+    #[serde(skip)]
+    name: String,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+
+    /// A day in each week of the term which has no classes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub breaks: Vec<NaiveDate>,
+}
+
+impl Term {
+    pub fn new(
+        settings: &Settings,
+        name: String,
+        start: NaiveDate,
+        end: NaiveDate,
+        breaks: Vec<NaiveDate>,
+    ) -> color_eyre::Result<Self> {
+        if end < start {
+            Err(Error::TermEndsBeforeStart(name.clone()))?;
+        }
+
+        let term = Self {
+            name,
+            start,
+            end,
+            breaks,
+        };
+
+        term.create(settings)?;
+
+        Ok(term)
+    }
+
+    pub fn create(&self, settings: &Settings) -> color_eyre::Result<()> {
+        let path = self.path(settings);
+
+        if path.exists() {
+            Err(Error::TermAlreadyExists(self.name.clone()))?;
+        }
+
+        fs::create_dir_all(settings.term_dir())?;
+
+        self.write(settings)
+    }
+
+    pub fn write(&self, settings: &Settings) -> color_eyre::Result<()> {
+        fs::write(self.path(settings), serde_yaml::to_string(&self)?)?;
+
+        Ok(())
+    }
+
+    pub fn open(settings: &Settings, name: &str) -> color_eyre::Result<Option<Self>> {
+        let path = settings.term_path(name);
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let yaml = fs::read_to_string(path)?;
+
+        Ok(Some(Self {
+            name: name.to_owned(),
+            ..serde_yaml::from_str(&yaml)?
+        }))
+    }
+
+    /// All terms, ordered by their start date.
+    pub fn all(settings: &Settings) -> color_eyre::Result<Vec<Self>> {
+        let dir = settings.term_dir();
+
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut terms = Vec::new();
+
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+
+            if path.extension().map_or(true, |ext| ext != "yml") {
+                continue;
+            }
+
+            let Some(name) = path.file_stem().map(|name| name.to_string_lossy()) else {
+                continue;
+            };
+
+            if let Some(term) = Self::open(settings, &name)? {
+                terms.push(term);
+            }
+        }
+
+        terms.sort_by_key(|term| term.start);
+
+        Ok(terms)
+    }
+
+    /// The term set with `term set`, or otherwise the term which today falls in.
+    pub fn current(settings: &Settings) -> color_eyre::Result<Option<Self>> {
+        if let Some(name) = &settings.current_term {
+            return Self::open(settings, name)?
+                .map(Some)
+                .ok_or_else(|| Error::TermDoesNotExist(name.clone()).into());
+        }
+
+        let today = Local::now().date_naive();
+
+        Ok(Self::all(settings)?
+            .into_iter()
+            .find(|term| term.contains(today)))
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start <= date && date <= self.end
+    }
+
+    pub fn is_break(&self, date: NaiveDate) -> bool {
+        self.break_weeks().contains(&week_start(date))
+    }
+
+    /// The teaching week `date` falls in, starting at 1 and skipping break weeks.
+    ///
+    /// Returns `None` if `date` is outside the term or during a break.
+    pub fn week(&self, date: NaiveDate) -> Option<i64> {
+        if !self.contains(date) || self.is_break(date) {
+            return None;
+        }
+
+        let first = week_start(self.start);
+        let current = week_start(date);
+
+        let breaks = self.break_weeks().range(first..current).count();
+
+        Some((current - first).num_weeks() + 1 - i64::try_from(breaks).ok()?)
+    }
+
+    fn break_weeks(&self) -> BTreeSet<NaiveDate> {
+        self.breaks.iter().copied().map(week_start).collect()
+    }
+
+    pub fn path(&self, settings: &Settings) -> PathBuf {
+        settings.term_path(&self.name)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// The Monday of the week `date` falls in.
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::{week_start, Term};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).expect("date should be valid")
+    }
+
+    /// Wednesday 2023-09-06 to Friday 2023-12-08, with a break in the week of 2023-10-09.
+    fn term() -> Term {
+        Term {
+            name: "fall-2023".to_owned(),
+            start: date(2023, 9, 6),
+            end: date(2023, 12, 8),
+            breaks: vec![date(2023, 10, 11)],
+        }
+    }
+
+    #[test]
+    fn week_start_is_monday() {
+        assert_eq!(week_start(date(2023, 9, 6)), date(2023, 9, 4));
+        assert_eq!(week_start(date(2023, 9, 4)), date(2023, 9, 4));
+        assert_eq!(week_start(date(2023, 9, 10)), date(2023, 9, 4));
+    }
+
+    #[test]
+    fn first_week() {
+        let term = term();
+
+        assert_eq!(term.week(date(2023, 9, 6)), Some(1));
+        assert_eq!(term.week(date(2023, 9, 10)), Some(1));
+        assert_eq!(term.week(date(2023, 9, 11)), Some(2));
+    }
+
+    #[test]
+    fn break_weeks_are_skipped() {
+        let term = term();
+
+        assert_eq!(term.week(date(2023, 10, 6)), Some(5));
+        assert_eq!(term.week(date(2023, 10, 9)), None);
+        assert_eq!(term.week(date(2023, 10, 15)), None);
+        assert_eq!(term.week(date(2023, 10, 16)), Some(6));
+    }
+
+    #[test]
+    fn last_day_of_term() {
+        let term = term();
+
+        assert!(term.contains(date(2023, 12, 8)));
+        assert_eq!(term.week(date(2023, 12, 8)), Some(13));
+    }
+
+    #[test]
+    fn before_term_starts() {
+        let term = term();
+
+        assert!(!term.contains(date(2023, 9, 5)));
+        assert_eq!(term.week(date(2023, 9, 5)), None);
+        assert_eq!(term.week(date(2023, 9, 4)), None);
+    }
+
+    #[test]
+    fn after_term_ends() {
+        let term = term();
+
+        assert!(!term.contains(date(2023, 12, 9)));
+        assert_eq!(term.week(date(2023, 12, 9)), None);
+    }
+}
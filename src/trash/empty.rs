@@ -0,0 +1,50 @@
+use clap::Parser;
+
+use super::TrashedCourse;
+use crate::{prompt, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    /// Only delete trashed courses with this course code
+    course_code: Option<String>,
+
+    /// Do not ask for confirmation
+    #[clap(short, long)]
+    yes: bool,
+}
+
+pub fn run(settings: &Settings, Args { course_code, yes }: Args) -> color_eyre::Result<()> {
+    let trashed = TrashedCourse::all(settings)?
+        .into_iter()
+        .filter(|trashed| {
+            course_code
+                .as_ref()
+                .map_or(true, |code| code == trashed.code())
+        })
+        .collect::<Vec<_>>();
+
+    if trashed.is_empty() {
+        println!("Trash is empty");
+
+        return Ok(());
+    }
+
+    if !yes
+        && !prompt::confirm(&format!(
+            "Permanently delete {} course(s) from the trash?",
+            trashed.len()
+        ))?
+    {
+        println!("Aborted");
+
+        return Ok(());
+    }
+
+    for trashed in trashed {
+        trashed.delete(settings)?;
+
+        println!("Deleted course: {}", trashed.code());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use clap::Parser;
+use serde::Serialize;
+
+use super::TrashedCourse;
+use crate::{format::Table, Format, Settings};
+
+#[derive(Parser)]
+pub struct Args {}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Listing {
+    code: String,
+    deleted_at: String,
+}
+
+pub fn run(settings: &Settings, format: Format, _: Args) -> color_eyre::Result<()> {
+    let listings = TrashedCourse::all(settings)?
+        .into_iter()
+        .map(|trashed| Listing {
+            code: trashed.code().to_owned(),
+            deleted_at: trashed.deleted_at().format("%Y-%m-%d %H:%M:%S").to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    format.print(&listings, |listings| {
+        let mut table = Table::new(vec!["code", "deleted at"]);
+
+        for listing in listings {
+            table.push(vec![listing.code.clone(), listing.deleted_at.clone()]);
+        }
+
+        table.to_string()
+    })
+}
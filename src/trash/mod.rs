@@ -0,0 +1,37 @@
+mod empty;
+mod list;
+mod restore;
+mod types;
+
+use clap::Parser;
+
+pub use self::types::TrashedCourse;
+
+use crate::{Format, Settings};
+
+#[derive(Parser)]
+pub struct Args {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Parser)]
+enum Command {
+    /// List all courses in the trash
+    #[clap(aliases = ["ls"])]
+    List(list::Args),
+
+    /// Restore a course from the trash
+    Restore(restore::Args),
+
+    /// Permanently delete courses in the trash
+    Empty(empty::Args),
+}
+
+pub fn run(settings: &Settings, format: Format, args: Args) -> color_eyre::Result<()> {
+    match args.command {
+        Command::List(args) => list::run(settings, format, args),
+        Command::Restore(args) => restore::run(settings, args),
+        Command::Empty(args) => empty::run(settings, args),
+    }
+}
@@ -0,0 +1,20 @@
+use clap::Parser;
+
+use super::TrashedCourse;
+use crate::Settings;
+
+#[derive(Parser)]
+pub struct Args {
+    /// The course code of the course to restore (the most recently removed one is used)
+    course_code: String,
+}
+
+pub fn run(settings: &Settings, Args { course_code }: Args) -> color_eyre::Result<()> {
+    let trashed = TrashedCourse::latest(settings, &course_code)?;
+
+    trashed.restore(settings)?;
+
+    println!("Restored course: {}", trashed.code());
+
+    Ok(())
+}
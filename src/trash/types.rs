@@ -0,0 +1,115 @@
+use std::{fs, path::PathBuf};
+
+use chrono::{Duration, Local, NaiveDateTime};
+
+use crate::{Course, Error, Settings};
+
+/// `%.f` keeps the fraction of a second so that courses trashed in quick succession get distinct
+/// directories, while still parsing the whole-second names of older trash entries.
+const TIMESTAMP_FORMAT: &str = "%Y%m%dT%H%M%S%.f";
+
+/// A course which has been moved into the trash.
+pub struct TrashedCourse {
+    code: String,
+    deleted_at: NaiveDateTime,
+}
+
+impl TrashedCourse {
+    /// Move `course` into the trash.
+    pub fn trash(settings: &Settings, course: &Course) -> color_eyre::Result<Self> {
+        let mut trashed = Self {
+            code: course.code().to_owned(),
+            deleted_at: Local::now().naive_local(),
+        };
+
+        while trashed.dir(settings).exists() {
+            trashed.deleted_at += Duration::nanoseconds(1);
+        }
+
+        fs::create_dir_all(settings.trash_dir())?;
+        fs::rename(course.dir(settings), trashed.dir(settings))?;
+
+        Ok(trashed)
+    }
+
+    /// Every course in the trash, oldest first.
+    pub fn all(settings: &Settings) -> color_eyre::Result<Vec<Self>> {
+        let trash_dir = settings.trash_dir();
+
+        if !trash_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut trashed = Vec::new();
+
+        for entry in fs::read_dir(trash_dir)? {
+            let path = entry?.path();
+
+            let Some(file_name) = path.file_name().map(|name| name.to_string_lossy()) else {
+                continue;
+            };
+
+            let Some((code, timestamp)) = file_name.rsplit_once('@') else {
+                continue;
+            };
+
+            let Ok(deleted_at) = NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT) else {
+                continue;
+            };
+
+            trashed.push(Self {
+                code: code.to_owned(),
+                deleted_at,
+            });
+        }
+
+        trashed.sort_by_key(|trashed| trashed.deleted_at);
+
+        Ok(trashed)
+    }
+
+    /// The most recently trashed course with `code`.
+    pub fn latest(settings: &Settings, code: &str) -> color_eyre::Result<Self> {
+        Self::all(settings)?
+            .into_iter()
+            .rev()
+            .find(|trashed| trashed.code == code)
+            .ok_or_else(|| Error::CourseNotInTrash(code.to_owned()).into())
+    }
+
+    /// Move the course back out of the trash.
+    pub fn restore(&self, settings: &Settings) -> color_eyre::Result<()> {
+        let dir = settings.course_dir(&self.code);
+
+        if dir.exists() {
+            Err(Error::CourseAlreadyExists(self.code.clone()))?;
+        }
+
+        fs::rename(self.dir(settings), dir)?;
+
+        Ok(())
+    }
+
+    /// Permanently delete the course.
+    pub fn delete(&self, settings: &Settings) -> color_eyre::Result<()> {
+        fs::remove_dir_all(self.dir(settings))?;
+
+        Ok(())
+    }
+
+    pub fn dir(&self, settings: &Settings) -> PathBuf {
+        settings.trash_dir().join(format!(
+            "{}@{}",
+            self.code,
+            self.deleted_at.format(TIMESTAMP_FORMAT)
+        ))
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub const fn deleted_at(&self) -> NaiveDateTime {
+        self.deleted_at
+    }
+}